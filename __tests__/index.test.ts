@@ -1,8 +1,58 @@
-import { translateToUnicode } from 'braillify'
+import {
+  decode,
+  encodeInto,
+  requiredLen,
+  translateFromUnicode,
+  translateToUnicode,
+} from 'braillify'
 
 describe('index', () => {
   it('should translate to unicode', () => {
     const result = translateToUnicode('안녕하세요.')
     expect(result).toBe('⠣⠒⠉⠻⠚⠠⠝⠬⠲')
   })
+
+  it('should throw an error with the character and its offsets', () => {
+    expect(() => translateToUnicode('가😀')).toThrow(
+      expect.objectContaining({
+        name: 'UnsupportedCharacterError',
+        message: "Unsupported character '😀' at char 1 (byte 3)",
+        character: '😀',
+        charOffset: 1,
+        byteOffset: 3,
+      }),
+    )
+  })
+
+  it('should throw an error for invalid braille', () => {
+    expect(() => decode(new Uint8Array([1, 64]))).toThrow(
+      expect.objectContaining({
+        name: 'InvalidBrailleCellError',
+        message: 'Invalid braille cell 64 at index 1',
+        cell: 64,
+        index: 1,
+      }),
+    )
+    expect(() => translateFromUnicode('⠁a')).toThrow(
+      expect.objectContaining({
+        name: 'InvalidBrailleCharacterError',
+        character: 'a',
+        charOffset: 1,
+        byteOffset: 3,
+      }),
+    )
+  })
+
+  it('should encode into a buffer', () => {
+    const buffer = new Uint8Array(requiredLen('가'))
+    expect(encodeInto('가', buffer)).toBe(1)
+    expect(buffer[0]).toBe(43)
+    expect(() => encodeInto('가나다', new Uint8Array(1))).toThrow(
+      expect.objectContaining({
+        name: 'BufferTooSmallError',
+        required: 3,
+        capacity: 1,
+      }),
+    )
+  })
 })
@@ -0,0 +1,54 @@
+//! 긴 입력의 점역 시간을 잽니다.
+//!
+//! 입력 크기를 64 KiB 에서 1 MiB 까지 늘려도 처리량(바이트/초)이 일정하면 점역 시간이 입력 길이에
+//! 비례하는 것입니다. 띄어쓰기 없는 긴 단어(URL, 염기 서열, base64)와 일반 한국어 문장을 함께 잽니다.
+//!
+//! ```sh
+//! cargo bench -p braillify --bench encode
+//! ```
+
+use std::hint::black_box;
+
+use braillify::encode;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+const SIZES: [usize; 3] = [64 * 1024, 256 * 1024, 1024 * 1024];
+
+/// `unit` 을 반복해 `size` 바이트 이하의 입력을 만듭니다.
+fn repeat(prefix: &str, unit: &str, size: usize) -> String {
+    let mut text = String::with_capacity(size);
+    text.push_str(prefix);
+    while text.len() + unit.len() <= size {
+        text.push_str(unit);
+    }
+    text
+}
+
+fn inputs(size: usize) -> [(&'static str, String); 4] {
+    [
+        (
+            "url",
+            repeat("https://example.com/", "path/to/page-1.html?", size),
+        ),
+        ("dna", repeat("", "ACGTTGCA", size)),
+        ("base64", repeat("", "QmFpbGxpZnk0+a/z9==", size)),
+        ("korean", repeat("", "안녕하세요 반갑습니다. ", size)),
+    ]
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+    group.sample_size(10);
+    for size in SIZES {
+        for (name, text) in inputs(size) {
+            group.throughput(Throughput::Bytes(text.len() as u64));
+            group.bench_with_input(BenchmarkId::new(name, size), &text, |b, text| {
+                b.iter(|| encode(black_box(text)).unwrap())
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_encode);
+criterion_main!(benches);
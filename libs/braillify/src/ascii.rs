@@ -0,0 +1,63 @@
+use alloc::string::String;
+
+/// 북미 ASCII 점자(North American ASCII Braille) 표
+///
+/// 셀 값(1점=1, 2점=2, 3점=4, 4점=8, 5점=16, 6점=32)을 인덱스로 한 문자이며,
+/// braillove-case-collector 의 `converter.py` 와 같은 표입니다.
+const PATTERN: &[u8; 64] = b" a1b'k2l`cif/msp\"e3h9o6r^djg>ntq,*5<-u8v.%[$+x!&;:4\\0z7(_?w]#y)=";
+
+/// 셀을 ASCII 점자 문자로 바꿉니다. 줄바꿈(255)은 `'\n'` 입니다.
+pub fn encode_ascii(cell: u8) -> char {
+    if cell == 255 {
+        return '\n';
+    }
+    PATTERN[cell as usize] as char
+}
+
+/// 셀을 `.brf` 파일 내용으로 바꿉니다.
+///
+/// 점자 파일 관례에 따라 줄은 CRLF 로 끝나며, 마지막 줄에도 CRLF 를 붙입니다.
+pub fn encode_brf(cells: &[u8]) -> String {
+    let mut result = String::with_capacity(cells.len() + 2);
+    for &cell in cells {
+        if cell == 255 {
+            result.push_str("\r\n");
+        } else {
+            result.push(encode_ascii(cell));
+        }
+    }
+    if !cells.is_empty() && cells.last() != Some(&255) {
+        result.push_str("\r\n");
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    pub fn test_encode_ascii() {
+        let chars = (0..64).map(encode_ascii).collect::<HashSet<_>>();
+        assert_eq!(chars.len(), 64);
+        assert!(chars.iter().all(|c| c.is_ascii() && !c.is_ascii_control()));
+
+        assert_eq!(encode_ascii(0), ' ');
+        assert_eq!(encode_ascii(1), 'a');
+        assert_eq!(encode_ascii(8), '`');
+        assert_eq!(encode_ascii(50), '4');
+        assert_eq!(encode_ascii(60), '#');
+        assert_eq!(encode_ascii(63), '=');
+        assert_eq!(encode_ascii(255), '\n');
+    }
+
+    #[test]
+    pub fn test_encode_brf() {
+        assert_eq!(encode_brf(&[]), "");
+        assert_eq!(encode_brf(&[10, 13, 10, 14, 40, 21]), "imis.o\r\n");
+        assert_eq!(encode_brf(&[1, 255, 3]), "a\r\nb\r\n");
+        assert_eq!(encode_brf(&[1, 255]), "a\r\n");
+    }
+}
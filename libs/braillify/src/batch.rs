@@ -0,0 +1,178 @@
+use alloc::{borrow::Cow, vec::Vec};
+use core::ops::Range;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{Encoder, error::BrailleError, normalize, options::EncodeOptions};
+
+/// 문단 사이를 나누는 빈 줄
+const PARAGRAPH_SEPARATOR: &str = "\n\n";
+
+/// 여러 글을 한꺼번에 점역합니다. 결과는 `texts` 와 같은 순서입니다.
+///
+/// `rayon` 기능을 켜면 글마다 다른 스레드에서 점역합니다.
+///
+/// ```
+/// let results = braillify::encode_batch(&["안녕하세요", "점자"]);
+/// assert_eq!(results[0], braillify::encode("안녕하세요"));
+/// assert_eq!(results[1], braillify::encode("점자"));
+/// ```
+pub fn encode_batch(texts: &[&str]) -> Vec<Result<Vec<u8>, BrailleError>> {
+    encode_batch_with_options(texts, &EncodeOptions::default())
+}
+
+pub fn encode_batch_with_options(
+    texts: &[&str],
+    options: &EncodeOptions,
+) -> Vec<Result<Vec<u8>, BrailleError>> {
+    #[cfg(feature = "rayon")]
+    let texts = texts.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let texts = texts.iter();
+    texts
+        .map(|text| crate::encode_with_options(text, options))
+        .collect()
+}
+
+/// 긴 문서를 빈 줄(`"\n\n"`)이 있는 곳에서 나누어 점역하여 차례로 잇습니다.
+///
+/// 결과는 `encode` 와 같습니다. 빈 줄이 든 단어 뒤에서 문서를 나누어 따로 점역하며, 나눈 곳에서
+/// 로마자나 대문자 구절, 따옴표가 이어지면 뒷부분은 앞부분의 상태를 이어받아 다시 점역합니다.
+///
+/// `rayon` 기능을 켜면 나눈 부분마다 다른 스레드에서 점역합니다.
+///
+/// ```
+/// let text = "첫째 문단입니다.\n\n둘째 문단입니다.";
+/// assert_eq!(braillify::encode_document(text), braillify::encode(text));
+/// ```
+pub fn encode_document(text: &str) -> Result<Vec<u8>, BrailleError> {
+    encode_document_with_options(text, &EncodeOptions::default())
+}
+
+pub fn encode_document_with_options(
+    text: &str,
+    options: &EncodeOptions,
+) -> Result<Vec<u8>, BrailleError> {
+    let normalized = if options.normalize {
+        normalize::normalize(text)
+    } else {
+        Cow::Borrowed(text)
+    };
+    encode_parts(&normalized, options).map_err(|e| match normalized {
+        // 오류 위치는 정규화하기 전의 원문 기준으로 돌려줍니다.
+        Cow::Owned(_) => e.map_offset(|offset| normalize::original_offset(text, offset)),
+        Cow::Borrowed(_) => e,
+    })
+}
+
+/// 빈 줄이 든 단어 뒤, 다음 단어가 시작하는 곳마다 나눈 바이트 범위
+fn parts(text: &str) -> Vec<Range<usize>> {
+    let mut starts = text
+        .match_indices(PARAGRAPH_SEPARATOR)
+        .filter_map(|(separator, _)| text[separator..].find(' ').map(|end| separator + end + 1))
+        .collect::<Vec<_>>();
+    starts.dedup();
+    core::iter::once(0)
+        .chain(starts.iter().copied())
+        .zip(starts.iter().copied().chain([text.len()]))
+        .map(|(start, end)| start..end)
+        .collect()
+}
+
+fn encode_parts(text: &str, options: &EncodeOptions) -> Result<Vec<u8>, BrailleError> {
+    let english_indicator = options.english_indicator(text);
+    let encode_part = |encoder: Option<Encoder>, range: &Range<usize>| {
+        let mut encoder = encoder.unwrap_or_else(|| {
+            let mut encoder = Encoder::with_options(options.clone());
+            encoder.english_indicator = english_indicator;
+            // 나눈 곳 앞에는 빈 줄이 든 단어가 있습니다.
+            encoder.has_processed_word = range.start > 0;
+            encoder
+        });
+        let mut cells = Vec::new();
+        encoder.encode_word_range(text, range.clone(), &mut cells, &mut |_| Ok(()))?;
+        Ok((cells, encoder))
+    };
+    let parts = parts(text);
+
+    #[cfg(feature = "rayon")]
+    let iter = parts.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let iter = parts.iter();
+    let encoded = iter
+        .map(|range| encode_part(None, range))
+        .collect::<Vec<Result<(Vec<u8>, Encoder), BrailleError>>>();
+
+    let mut result = Vec::with_capacity(text.len() * 2);
+    let mut prev: Option<Encoder> = None;
+    for (range, part) in parts.iter().zip(encoded) {
+        let (cells, encoder) = match prev {
+            // 앞부분에서 이어지는 상태가 있으면 그 상태로 다시 점역합니다.
+            Some(prev) if !prev.is_clear() => encode_part(Some(prev), range)?,
+            _ => part?,
+        };
+        result.extend(cells);
+        prev = Some(encoder);
+    }
+    if let Some(mut encoder) = prev {
+        encoder.finish(&mut result)?;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Context, Newline, encode, encode_with_options, test::test_cases};
+
+    #[test]
+    pub fn test_encode_batch() {
+        let texts = ["안녕하세요", "ABC", "😀", ""];
+        let results = encode_batch(&texts);
+        assert_eq!(results.len(), texts.len());
+        for (text, result) in texts.iter().zip(results) {
+            assert_eq!(result, encode(text));
+        }
+    }
+
+    #[test]
+    pub fn test_encode_document() {
+        for text in [
+            "상상이상의 \n\nTable of Contents\n\nABC DEF GHI 가\n\n1,000원",
+            "a\n\nb 가",
+            "ABC DEF GHI\n\nJKL 가",
+            "ABC DEF GHI \n\nJKL MNO 가",
+            "가 \"나\n\n다\" 라",
+            "가 \"나 \n\n다\" 라",
+            "가\n\n그래서 나는\n\n\n\n 다",
+            "가나",
+            "",
+            "\n\n",
+        ] {
+            assert_eq!(encode_document(text), encode(text), "{text:?}");
+            for options in [
+                EncodeOptions::new().newline(Newline::Space),
+                EncodeOptions::new().context(Context::English),
+            ] {
+                assert_eq!(
+                    encode_document_with_options(text, &options),
+                    encode_with_options(text, &options),
+                    "{text:?}"
+                );
+            }
+        }
+        let cases = test_cases();
+        for pair in cases.windows(2) {
+            for separator in ["\n\n", "\n\n ", " \n\n"] {
+                let text = [pair[0].0.as_str(), pair[1].0.as_str()].join(separator);
+                assert_eq!(encode_document(&text), encode(&text), "{text:?}");
+            }
+        }
+        // 오류 위치는 정규화하기 전의 원문 기준입니다.
+        let text = "가나\n\n 다😀";
+        assert_eq!(encode_document(text), encode(text));
+        let text = "ᄀᆞ\n\n 다😀";
+        assert_eq!(encode_document(text), encode(text));
+    }
+}
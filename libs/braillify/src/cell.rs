@@ -0,0 +1,265 @@
+use core::fmt;
+
+use crate::{
+    ascii::encode_ascii,
+    error::{BrailleError, Offset},
+};
+
+/// 6점 점자 한 칸
+///
+/// 1점부터 6점까지를 하위 6비트(1점=1, 2점=2, 3점=4, 4점=8, 5점=16, 6점=32)로 담습니다.
+///
+/// ```
+/// use braillify::BrailleCell;
+///
+/// let cell = BrailleCell::from_dots(&[1, 2, 5]).unwrap();
+/// assert!(cell.has_dot(5));
+/// assert!(!cell.has_dot(3));
+/// assert_eq!(cell.to_unicode(), '⠓');
+/// assert_eq!(BrailleCell::try_from('⠓').unwrap(), cell);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u8", into = "u8")
+)]
+pub struct BrailleCell(u8);
+
+impl BrailleCell {
+    /// 점이 없는 칸
+    pub const EMPTY: Self = Self(0);
+
+    /// 점 비트로 칸을 만듭니다. 6점 범위(0..64)를 벗어나면 `None` 입니다.
+    pub const fn new(bits: u8) -> Option<Self> {
+        if bits < 64 { Some(Self(bits)) } else { None }
+    }
+
+    /// 점 번호(1..=6)로 칸을 만듭니다. 범위를 벗어난 점 번호가 있으면 `None` 입니다.
+    pub fn from_dots(dots: &[u8]) -> Option<Self> {
+        dots.iter().try_fold(Self::EMPTY, |cell, dot| match dot {
+            1..=6 => Some(Self(cell.0 | 1 << (dot - 1))),
+            _ => None,
+        })
+    }
+
+    /// 점 비트
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// `dot` 번 점이 찍혀 있는지 확인합니다.
+    pub const fn has_dot(self, dot: u8) -> bool {
+        matches!(dot, 1..=6) && self.0 & (1 << (dot - 1)) != 0
+    }
+
+    /// 찍힌 점 번호를 작은 것부터 돌려줍니다.
+    pub fn dots(self) -> impl Iterator<Item = u8> {
+        (1..=6).filter(move |dot| self.has_dot(*dot))
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// 유니코드 점자(U+2800..U+283F)
+    pub fn to_unicode(self) -> char {
+        char::from_u32(0x2800 + self.0 as u32).unwrap()
+    }
+
+    /// 북미 ASCII 점자
+    pub fn to_ascii(self) -> char {
+        encode_ascii(self.0)
+    }
+}
+
+impl TryFrom<u8> for BrailleCell {
+    type Error = BrailleError;
+
+    fn try_from(bits: u8) -> Result<Self, Self::Error> {
+        Self::new(bits).ok_or(BrailleError::InvalidBrailleCell {
+            cell: bits,
+            index: 0,
+        })
+    }
+}
+
+impl TryFrom<char> for BrailleCell {
+    type Error = BrailleError;
+
+    /// 6점 유니코드 점자(U+2800..U+283F)만 바꿀 수 있습니다.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '\u{2800}'..='\u{283F}' => Ok(Self((c as u32 - 0x2800) as u8)),
+            _ => Err(BrailleError::InvalidBrailleCharacter {
+                character: c,
+                offset: Offset::default(),
+            }),
+        }
+    }
+}
+
+impl From<BrailleCell> for u8 {
+    fn from(cell: BrailleCell) -> Self {
+        cell.0
+    }
+}
+
+impl From<BrailleCell> for char {
+    fn from(cell: BrailleCell) -> Self {
+        cell.to_unicode()
+    }
+}
+
+impl fmt::Display for BrailleCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_unicode())
+    }
+}
+
+/// 점역 결과의 한 단위
+///
+/// 점자 칸과 빈칸, 줄바꿈, 쪽바꿈을 구분합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BrailleToken {
+    Cell(BrailleCell),
+    /// 띄어쓰기 (점이 없는 칸)
+    Space,
+    /// 줄바꿈
+    LineBreak,
+    /// 쪽바꿈
+    PageBreak,
+}
+
+impl BrailleToken {
+    /// `encode` 가 돌려주는 셀 값을 바꿉니다. 0 은 `Space`, 255 는 `LineBreak` 입니다.
+    pub fn from_raw(cell: u8) -> Result<Self, BrailleError> {
+        match cell {
+            0 => Ok(Self::Space),
+            255 => Ok(Self::LineBreak),
+            _ => BrailleCell::try_from(cell).map(Self::Cell),
+        }
+    }
+
+    /// `encode` 가 돌려주는 셀 값, 쪽바꿈도 줄바꿈과 같은 255 입니다.
+    pub fn to_raw(self) -> u8 {
+        match self {
+            Self::Cell(cell) => cell.bits(),
+            Self::Space => 0,
+            Self::LineBreak | Self::PageBreak => 255,
+        }
+    }
+
+    /// 유니코드 점자, 줄바꿈은 `'\n'`, 쪽바꿈은 폼 피드 `'\u{c}'` 입니다.
+    pub fn to_unicode(self) -> char {
+        match self {
+            Self::Cell(cell) => cell.to_unicode(),
+            Self::Space => BrailleCell::EMPTY.to_unicode(),
+            Self::LineBreak => '\n',
+            Self::PageBreak => '\u{c}',
+        }
+    }
+
+    /// 북미 ASCII 점자, 줄바꿈은 `'\n'`, 쪽바꿈은 폼 피드 `'\u{c}'` 입니다.
+    pub fn to_ascii(self) -> char {
+        match self {
+            Self::Cell(cell) => cell.to_ascii(),
+            Self::Space => ' ',
+            Self::LineBreak => '\n',
+            Self::PageBreak => '\u{c}',
+        }
+    }
+}
+
+impl From<BrailleCell> for BrailleToken {
+    fn from(cell: BrailleCell) -> Self {
+        if cell.is_empty() {
+            Self::Space
+        } else {
+            Self::Cell(cell)
+        }
+    }
+}
+
+impl TryFrom<char> for BrailleToken {
+    type Error = BrailleError;
+
+    /// 유니코드 점자와 함께 `' '`, `'\n'`, `'\u{c}'` 를 바꿀 수 있습니다.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            ' ' => Ok(Self::Space),
+            '\n' => Ok(Self::LineBreak),
+            '\u{c}' => Ok(Self::PageBreak),
+            _ => BrailleCell::try_from(c).map(Self::from),
+        }
+    }
+}
+
+impl fmt::Display for BrailleToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_unicode())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_cell() {
+        let cell = BrailleCell::from_dots(&[1, 2, 5]).unwrap();
+        assert_eq!(cell.bits(), 19);
+        assert_eq!(cell.dots().collect::<Vec<_>>(), vec![1, 2, 5]);
+        assert!(cell.has_dot(1) && cell.has_dot(2) && cell.has_dot(5));
+        assert!(!cell.has_dot(3) && !cell.has_dot(0) && !cell.has_dot(7));
+        assert_eq!(BrailleCell::from_dots(&[]), Some(BrailleCell::EMPTY));
+        assert_eq!(BrailleCell::from_dots(&[1, 7]), None);
+        assert_eq!(BrailleCell::new(64), None);
+        for bits in 0..64 {
+            let cell = BrailleCell::new(bits).unwrap();
+            assert_eq!(
+                BrailleCell::from_dots(&cell.dots().collect::<Vec<_>>()),
+                Some(cell)
+            );
+            assert_eq!(BrailleCell::try_from(cell.to_unicode()), Ok(cell));
+        }
+        assert_eq!(
+            BrailleCell::try_from(255u8),
+            Err(BrailleError::InvalidBrailleCell {
+                cell: 255,
+                index: 0
+            })
+        );
+        assert_eq!(
+            BrailleCell::try_from('a'),
+            Err(BrailleError::InvalidBrailleCharacter {
+                character: 'a',
+                offset: Offset::default(),
+            })
+        );
+        assert_eq!(cell.to_string(), "⠓");
+        assert_eq!(cell.to_ascii(), 'h');
+    }
+
+    #[test]
+    pub fn test_token() {
+        assert_eq!(BrailleToken::from_raw(0), Ok(BrailleToken::Space));
+        assert_eq!(BrailleToken::from_raw(255), Ok(BrailleToken::LineBreak));
+        assert_eq!(
+            BrailleToken::from_raw(19),
+            Ok(BrailleToken::Cell(BrailleCell::new(19).unwrap()))
+        );
+        assert!(BrailleToken::from_raw(64).is_err());
+        for c in ['⠀', ' ', '\n', '\u{c}', '⠓'] {
+            let token = BrailleToken::try_from(c).unwrap();
+            assert_eq!(BrailleToken::try_from(token.to_unicode()), Ok(token));
+        }
+        assert_eq!(BrailleToken::try_from('⠀'), Ok(BrailleToken::Space));
+        assert_eq!(BrailleToken::PageBreak.to_ascii(), '\u{c}');
+        for cell in [0, 19, 255] {
+            assert_eq!(BrailleToken::from_raw(cell).unwrap().to_raw(), cell);
+        }
+        assert_eq!(BrailleToken::PageBreak.to_raw(), 255);
+    }
+}
@@ -1,6 +1,6 @@
 use phf::phf_map;
 
-use crate::unicode::decode_unicode;
+use crate::{error::BrailleError, unicode::decode_unicode, utils::longest_match};
 
 pub static SHORTCUT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     '가' => &[decode_unicode('⠫')],
@@ -34,14 +34,18 @@ pub static SHORTCUT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     '청' => &[decode_unicode('⠰'), decode_unicode('⠻')],
 };
 
-pub fn encode_char_shortcut(text: char) -> Result<&'static [u8], String> {
+pub fn encode_char_shortcut(text: char) -> Result<&'static [u8], BrailleError> {
     if let Some(code) = SHORTCUT_MAP.get(&text) {
         Ok(code)
     } else {
-        Err("Invalid Korean char shortcut".to_string())
+        Err(BrailleError::invalid_korean(text))
     }
 }
 
+pub fn decode_char_shortcut(code: &[u8]) -> Option<(char, usize)> {
+    longest_match(SHORTCUT_MAP.entries().map(|(k, v)| (*k, *v)), code)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -64,4 +68,12 @@ mod test {
             &[decode_unicode('⠸'), decode_unicode('⠎')]
         );
     }
+
+    #[test]
+    pub fn test_decode_char_shortcut() {
+        for (key, value) in SHORTCUT_MAP.entries() {
+            assert_eq!(decode_char_shortcut(value), Some((*key, value.len())));
+        }
+        assert_eq!(decode_char_shortcut(&[decode_unicode('⠁')]), None);
+    }
 }
@@ -1,7 +1,13 @@
-use crate::{math_symbol_shortcut::is_math_symbol_char, symbol_shortcut::is_symbol_char};
+use crate::{
+    error::BrailleError,
+    greek::is_greek_char,
+    math_symbol_shortcut::is_math_symbol_char,
+    old_hangul::{is_old_hangul_char, is_old_hangul_part},
+    symbol_shortcut::is_symbol_char,
+};
 
 /// Character in Korean
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct KoreanChar {
     /// 초성
     pub cho: char,
@@ -12,10 +18,10 @@ pub struct KoreanChar {
 }
 
 impl KoreanChar {
-    pub fn new(c: char) -> Result<Self, String> {
+    pub fn new(c: char) -> Result<Self, BrailleError> {
         let code = c as u32;
-        if !(0xAC00 <= code && code <= 0xD7A3) {
-            return Err("Invalid Korean character".to_string());
+        if !(0xAC00..=0xD7A3).contains(&code) {
+            return Err(BrailleError::invalid_korean(c));
         }
 
         const CHOSEONG: [char; 19] = [
@@ -50,11 +56,15 @@ impl KoreanChar {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum CharType {
     Korean(KoreanChar),
     KoreanPart(char),
+    /// 옛한글 음절을 이루는 첫가끝 자모
+    OldHangul(char),
     English(char),
+    /// 그리스 문자
+    Greek(char),
     Number(char),
     Symbol(char),
     MathSymbol(char),
@@ -62,13 +72,16 @@ pub enum CharType {
 }
 
 impl CharType {
-    pub fn new(c: char) -> Result<Self, String> {
+    pub fn new(c: char) -> Result<Self, BrailleError> {
         if c.is_ascii_alphabetic() {
             return Ok(Self::English(c));
         }
         if c.is_ascii_digit() {
             return Ok(Self::Number(c));
         }
+        if is_greek_char(c) {
+            return Ok(Self::Greek(c));
+        }
         if is_symbol_char(c) {
             return Ok(Self::Symbol(c));
         }
@@ -76,19 +89,22 @@ impl CharType {
             return Ok(Self::MathSymbol(c));
         }
         let code = c as u32;
-        if 0x3131 <= code && code <= 0x3163 {
+        if (0x3131..=0x3163).contains(&code) || is_old_hangul_part(c) {
             return Ok(Self::KoreanPart(c));
         }
+        if is_old_hangul_char(c) {
+            return Ok(Self::OldHangul(c));
+        }
         // if !(0xAC00 <= code && code <= 0xD7A3) {
         //     return Ok(Self::Char(c));
         // }
-        if 0xAC00 <= code && code <= 0xD7A3 {
+        if (0xAC00..=0xD7A3).contains(&code) {
             return Ok(Self::Korean(KoreanChar::new(c)?));
         }
         if c.is_whitespace() {
             return Ok(Self::Space(c));
         }
-        Err("Invalid character".to_string())
+        Err(BrailleError::unsupported_character(c))
     }
 }
 
@@ -104,6 +120,7 @@ mod test {
             CharType::new('A').unwrap(),
             CharType::English('A')
         ));
+        assert!(matches!(CharType::new('Ω').unwrap(), CharType::Greek('Ω')));
         assert!(matches!(CharType::new('1').unwrap(), CharType::Number('1')));
         assert!(matches!(CharType::new('!').unwrap(), CharType::Symbol('!')));
         assert!(matches!(
@@ -111,6 +128,15 @@ mod test {
             CharType::KoreanPart('ㄱ')
         ));
         assert!(matches!(CharType::new(' ').unwrap(), CharType::Space(' ')));
+        assert!(matches!(
+            CharType::new('ㆍ').unwrap(),
+            CharType::KoreanPart('ㆍ')
+        ));
+        assert!(matches!(
+            CharType::new('\u{1140}').unwrap(),
+            CharType::OldHangul('\u{1140}')
+        ));
+        assert!(CharType::new('\u{3164}').is_err());
     }
 
     proptest! {
@@ -127,11 +153,17 @@ mod test {
                 }
                 CharType::KoreanPart(ch) => {
                     let code = ch as u32;
-                    assert!(0x3131 <= code && code <= 0x3163);
+                    assert!((0x3131..=0x3163).contains(&code) || is_old_hangul_part(ch));
+                }
+                CharType::OldHangul(ch) => {
+                    assert!(is_old_hangul_char(ch));
                 }
                 CharType::English(ch) => {
                     assert!(ch.is_ascii_alphabetic());
                 }
+                CharType::Greek(ch) => {
+                    assert!(is_greek_char(ch));
+                }
                 CharType::Number(ch) => {
                     assert!(ch.is_ascii_digit());
                 }
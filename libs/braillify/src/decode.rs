@@ -0,0 +1,627 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    char_shortcut::decode_char_shortcut,
+    char_struct::KoreanChar,
+    english::decode_english,
+    error::{BrailleError, Offset},
+    jauem::{choseong::decode_choseong, jongseong::decode_jongseong},
+    korean_part::decode_korean_part,
+    math_symbol_shortcut::decode_char_math_symbol_shortcut,
+    moeum::jungsong::decode_jungsong,
+    number::decode_number,
+    pairing::encode_quote,
+    rule_en::{decode_rule_en_10_4, decode_rule_en_10_6},
+    split::merge_korean_jauem,
+    symbol_shortcut::{decode_char_symbol_shortcut, encode_char_symbol_shortcut},
+    unicode::{encode_unicode, try_decode_unicode},
+    utils::{build_char, has_choseong_o},
+    word_shortcut::decode_word_shortcut,
+};
+
+/// ‘나, 다, 마, 바, 자, 카, 타’ 약자 뒤의 ⠌은 ‘ㅖ’가 아닌 받침 ‘ㅆ’으로 읽습니다. (났, 닸, 탔 등)
+/// ‘파’는 14항 [붙임]에 따라 ‘팠’을 ⠙⠣⠌으로 적으므로 ⠙⠌은 ‘폐’로 읽습니다.
+const SSANG_BATCHIM_CHOSEONG: [char; 7] = ['ㄴ', 'ㄷ', 'ㅁ', 'ㅂ', 'ㅈ', 'ㅋ', 'ㅌ'];
+
+/// 따옴표와 점형이 겹치는 괄호, 여는 괄호는 ⠦ 을 포함하고 닫는 괄호는 ⠴ 을 포함합니다.
+const OPENING_BRACKETS: [char; 5] = ['(', '{', '[', '「', '『'];
+const CLOSING_BRACKETS: [char; 5] = [')', '}', ']', '」', '』'];
+
+/// 점자 셀을 묵자로 역점역합니다.
+///
+/// 점자는 같은 점형이 여러 글자에 쓰이므로 역점역은 문맥에 따라 가장 자연스러운 글자를 고릅니다.
+/// 예를 들어 단어 끝의 ⠲, ⠖, ⠦ 은 받침 ‘ㅍ, ㅋ, ㅌ’이 아닌 문장 부호로 읽습니다.
+///
+/// 옛한글은 점역만 할 수 있습니다. 옛 자모 앞의 ⠸ 은 ‘것’ 약자와, 풀어 적은 첫가끝 자모는 현대 한글
+/// 자모와 점형이 같으므로 역점역하면 현대 한글로 읽습니다.
+/// 그리스 문자(31항)도 점역만 할 수 있으며, 그리스 문자표 ⠨ 은 ‘ㅈ’ 과 점형이 같으므로 한글로 읽습니다.
+pub struct Decoder {
+    is_english: bool,
+    triple_big_english: bool,
+    english_indicator: bool,
+    korean_contractions: bool,
+    /// 열려 있는 곧은 따옴표
+    quotes: Vec<char>,
+}
+
+impl Decoder {
+    /// `english_indicator` 가 true 이면 국어 문장으로 보고 로마자표 ⠴ 와 로마자 종료표 ⠲ 사이만 로마자로 읽습니다.
+    /// false 이면 `Encoder::new(false)` 의 출력과 같이 모든 글자를 로마자로 읽습니다.
+    pub fn new(english_indicator: bool) -> Self {
+        Self {
+            english_indicator,
+            is_english: false,
+            triple_big_english: false,
+            korean_contractions: true,
+            quotes: Vec::new(),
+        }
+    }
+
+    /// false 이면 풀어쓰기(`EncodeOptions::uncontracted`)로 적힌 점자로 보고 약자, 약어를 읽지 않습니다.
+    ///
+    /// 풀어쓰기에서는 ⠨⠌ 처럼 약자와 겹치는 점형을 모두 자모로 읽을 수 있습니다. (‘쨨’이 아닌 ‘졔’)
+    pub fn korean_contractions(mut self, enabled: bool) -> Self {
+        self.korean_contractions = enabled;
+        self
+    }
+
+    pub fn decode(&mut self, cells: &[u8], result: &mut String) -> Result<(), BrailleError> {
+        let mut start = 0;
+        for (i, cell) in cells.iter().enumerate() {
+            match cell {
+                0 | 255 => {
+                    self.decode_word(&cells[start..i], result);
+                    result.push(if *cell == 0 { ' ' } else { '\n' });
+                    start = i + 1;
+                }
+                64.. => {
+                    return Err(BrailleError::InvalidBrailleCell {
+                        cell: *cell,
+                        index: i,
+                    });
+                }
+                _ => {}
+            }
+        }
+        self.decode_word(&cells[start..], result);
+        Ok(())
+    }
+
+    /// 따옴표가 열려 있고 `rest` 가 닫는 따옴표로 시작하면 닫고 읽은 셀의 수를 돌려줍니다.
+    fn close_quote(&mut self, quote: char, rest: &[u8], result: &mut String) -> Option<usize> {
+        let close = encode_quote(quote, false)?;
+        let index = self.quotes.iter().rposition(|open| *open == quote)?;
+        if !rest.starts_with(close) {
+            return None;
+        }
+        self.quotes.truncate(index);
+        result.push(decoded_quote(quote, false));
+        Some(close.len())
+    }
+
+    fn decode_word(&mut self, word: &[u8], result: &mut String) {
+        // 닫는 큰따옴표 ⠴ 는 받침 ‘ㅎ’, 로마자표와 점형이 같으므로 따옴표가 열려 있을 때 단어 끝에서
+        // 닫는 따옴표로 읽습니다. 단어 끝의 닫는 괄호도 먼저 떼어 냅니다.
+        let mut open = self.quotes.clone();
+        open.extend(leading_quotes(word));
+        let mut word = word;
+        let mut closing = Vec::new();
+        loop {
+            if self.english_indicator
+                && let Some(c) = CLOSING_BRACKETS
+                    .into_iter()
+                    .find(|c| word.ends_with(symbol_cells(*c)))
+            {
+                word = &word[..word.len() - symbol_cells(c).len()];
+                closing.push(c);
+            } else if let Some(index) = open.iter().rposition(|quote| {
+                encode_quote(*quote, false).is_some_and(|close| word.ends_with(close))
+            }) {
+                let quote = open.remove(index);
+                word = &word[..word.len() - encode_quote(quote, false).map_or(0, <[u8]>::len)];
+                closing.push(quote);
+            } else {
+                break;
+            }
+        }
+        self.decode_cells(word, result);
+        for c in closing.into_iter().rev() {
+            if let Some(index) = self.quotes.iter().rposition(|quote| *quote == c) {
+                self.quotes.truncate(index);
+            }
+            result.push(decoded_quote(c, false));
+        }
+    }
+
+    fn decode_cells(&mut self, word: &[u8], result: &mut String) {
+        let word_start = result.len();
+        let mut i = 0;
+        let mut is_number = false;
+        let mut is_big_english = false;
+        let mut is_big_letter = false;
+        // 10.6 약자는 로마자가 시작하는 곳에서만 쓰인다
+        let mut english_start = true;
+        // 받침 없는 음절 바로 뒤인지 여부 (11항, 12항 구분표)
+        let mut open_syllable = false;
+        // 18항 약어는 단어 첫머리에서만 쓰인다
+        let mut shortcut_end = 0;
+
+        while i < word.len() {
+            let rest = &word[i..];
+
+            // 여는 따옴표는 단어 첫머리나 여는 부호 뒤에 옵니다.
+            if result[word_start..]
+                .chars()
+                .all(|c| c == '"' || c == '‘' || OPENING_BRACKETS.contains(&c))
+                && let Some((quote, len)) = opening_quote(rest)
+            {
+                self.quotes.push(quote);
+                result.push(decoded_quote(quote, true));
+                is_number = false;
+                open_syllable = false;
+                i += len;
+                continue;
+            }
+            if let Some(len) = self.close_quote('\'', rest, result) {
+                open_syllable = false;
+                i += len;
+                continue;
+            }
+
+            if is_number {
+                if let Some(c) = decode_number(rest[0]) {
+                    result.push(c);
+                    i += 1;
+                    continue;
+                }
+                if rest.len() > 1 && decode_number(rest[1]).is_some() {
+                    if rest[0] == 2 {
+                        // 제41항 숫자 사이에 붙어 나오는 쉼표와 자릿점은 ⠂으로 적는다.
+                        result.push(',');
+                        i += 1;
+                        continue;
+                    }
+                    if rest[0] == 50 {
+                        // 제43항 숫자 사이에 마침표, 쉼표, 연결표가 붙어 나올 때에는 뒤의 숫자에 수표를 적지 않는다.
+                        result.push('.');
+                        i += 1;
+                        continue;
+                    }
+                }
+                is_number = false;
+            }
+            if rest[0] == 60 {
+                // 제40항 숫자는 수표 ⠼을 앞세워 다음과 같이 적는다.
+                is_number = true;
+                open_syllable = false;
+                i += 1;
+                continue;
+            }
+
+            if self.is_english || !self.english_indicator {
+                // 28항 [붙임] 대문자 기호표 ⠠, 대문자 단어표 ⠠⠠, 대문자 구절표 ⠠⠠⠠, 대문자 종료표 ⠠⠄
+                match rest {
+                    [32, 32, 32, ..] => {
+                        self.triple_big_english = true;
+                        i += 3;
+                        continue;
+                    }
+                    [32, 4, ..] if self.triple_big_english => {
+                        self.triple_big_english = false;
+                        i += 2;
+                        continue;
+                    }
+                    [32, 32, next, ..] if is_english_cell(*next) => {
+                        is_big_english = true;
+                        i += 2;
+                        continue;
+                    }
+                    [32, next, ..] if is_english_cell(*next) => {
+                        is_big_letter = true;
+                        i += 1;
+                        continue;
+                    }
+                    _ => {}
+                }
+
+                let text = if let Some(c) = decode_english(rest[0]) {
+                    Some(c.to_string())
+                } else if english_start && rest.len() > 1 {
+                    decode_rule_en_10_6(rest[0])
+                        .or_else(|| decode_rule_en_10_4(rest[0]))
+                        .map(str::to_string)
+                } else {
+                    decode_rule_en_10_4(rest[0]).map(str::to_string)
+                };
+                if let Some(text) = text {
+                    if is_big_english || self.triple_big_english {
+                        result.push_str(&text.to_uppercase());
+                    } else if is_big_letter {
+                        let mut chars = text.chars();
+                        if let Some(first) = chars.next() {
+                            result.extend(first.to_uppercase());
+                            result.push_str(chars.as_str());
+                        }
+                    } else {
+                        result.push_str(&text);
+                    }
+                    is_big_letter = false;
+                    english_start = false;
+                    open_syllable = false;
+                    i += 1;
+                    continue;
+                }
+                is_big_english = false;
+                if self.english_indicator {
+                    let was_english = core::mem::replace(&mut self.is_english, false);
+                    if rest[0] == 50 {
+                        // 제29항 로마자 종료표
+                        i += 1;
+                        continue;
+                    }
+                    // 제34항 따옴표로 묶인 로마자는 종료표 없이 닫는 따옴표가 바로 이어집니다.
+                    if was_english && let Some(len) = self.close_quote('"', rest, result) {
+                        i += len;
+                        continue;
+                    }
+                }
+            }
+
+            if self.english_indicator {
+                if open_syllable && rest[0] == 36 && matches!(rest.get(1), Some(12 | 23)) {
+                    // 11항, 12항 모음자 사이의 구분표 ⠤
+                    open_syllable = false;
+                    i += 1;
+                    continue;
+                }
+                if rest[0] == 52 && !matches!(rest, [52, 4, ..] | [52, 2 | 6]) {
+                    // 제29항 국어 문장 안에 로마자가 나올 때에는 그 앞에 로마자표 ⠴을 적는다.
+                    self.is_english = true;
+                    english_start = true;
+                    open_syllable = false;
+                    i += 1;
+                    continue;
+                }
+                if i == shortcut_end
+                    && self.korean_contractions
+                    && let Some((text, len)) = decode_word_shortcut(rest)
+                {
+                    // 18항 약어
+                    result.push_str(text);
+                    open_syllable = false;
+                    i += len;
+                    shortcut_end = i;
+                    continue;
+                }
+                if matches!(rest[0], 63 | 56)
+                    && let Some((part, len)) = decode_korean_part(&rest[1..])
+                {
+                    let is_jauem = (0x3131..=0x314E).contains(&(part as u32));
+                    let next = &rest[1 + len..];
+                    // 8항 온표 ⠿ 뒤의 자모, 10항 ⠸ 뒤의 자음자
+                    // 모음자는 ‘옹’ 약자 뒤에 이어지는 음절일 수 있으므로 뒤에 음절이 없을 때에만 자모로 읽는다.
+                    if is_jauem
+                        || (rest[0] == 63
+                            && (next.first().is_none_or(|c| *c == 63)
+                                || decode_syllable(next, self.korean_contractions).is_none()))
+                    {
+                        result.push(part);
+                        open_syllable = false;
+                        i += 1 + len;
+                        continue;
+                    }
+                }
+                if let Some((syllable, len)) = decode_syllable(rest, self.korean_contractions) {
+                    result.push(syllable);
+                    open_syllable = KoreanChar::new(syllable).is_ok_and(|k| k.jong.is_none());
+                    i += len;
+                    continue;
+                }
+            }
+
+            open_syllable = false;
+            if let Some((symbol, len)) = decode_char_symbol_shortcut(rest) {
+                result.push(symbol);
+                i += len;
+            } else if let Some((symbol, len)) = decode_char_math_symbol_shortcut(rest) {
+                result.push(symbol);
+                i += len;
+            } else {
+                // 읽을 수 없는 점형은 그대로 남긴다.
+                result.push(encode_unicode(rest[0]));
+                i += 1;
+            }
+        }
+    }
+}
+
+/// 작은따옴표는 ‘ ’ 로 읽습니다.
+fn decoded_quote(quote: char, open: bool) -> char {
+    match (quote, open) {
+        ('\'', true) => '‘',
+        ('\'', false) => '’',
+        _ => quote,
+    }
+}
+
+fn symbol_cells(c: char) -> &'static [u8] {
+    encode_char_symbol_shortcut(c).unwrap_or_default()
+}
+
+/// `cells` 가 여는 괄호로 시작하면 (괄호, 셀 수)
+fn opening_bracket(cells: &[u8]) -> Option<(char, usize)> {
+    OPENING_BRACKETS
+        .into_iter()
+        .map(|c| (c, symbol_cells(c)))
+        .find(|(_, code)| cells.starts_with(code))
+        .map(|(c, code)| (c, code.len()))
+}
+
+/// `cells` 가 여는 따옴표로 시작하면 (따옴표, 셀 수)
+fn opening_quote(cells: &[u8]) -> Option<(char, usize)> {
+    if opening_bracket(cells).is_some() {
+        return None;
+    }
+    ['\'', '"'].into_iter().find_map(|quote| {
+        encode_quote(quote, true)
+            .filter(|open| cells.starts_with(open))
+            .map(|open| (quote, open.len()))
+    })
+}
+
+/// 단어 첫머리에서 여는 따옴표를 찾습니다.
+fn leading_quotes(word: &[u8]) -> Vec<char> {
+    let mut quotes = Vec::new();
+    let mut rest = word;
+    loop {
+        if let Some((_, len)) = opening_bracket(rest) {
+            rest = &rest[len..];
+        } else if let Some((quote, len)) = opening_quote(rest) {
+            rest = &rest[len..];
+            quotes.push(quote);
+        } else {
+            return quotes;
+        }
+    }
+}
+
+fn is_english_cell(code: u8) -> bool {
+    decode_english(code).is_some()
+        || decode_rule_en_10_4(code).is_some()
+        || decode_rule_en_10_6(code).is_some()
+}
+
+/// 한 음절을 읽습니다. `contractions` 가 false 이면 약자를 읽지 않습니다.
+fn decode_syllable(code: &[u8], contractions: bool) -> Option<(char, usize)> {
+    if code.first() == Some(&32) {
+        // 제2항 된소리 글자 앞의 된소리표 ⠠
+        if let Some((cho, jung, jong, len)) = decode_syllable_parts(&code[1..], contractions)
+            && let Some(double) = merge_korean_jauem(cho, cho)
+        {
+            return Some((build_char(double, jung, jong), len + 1));
+        }
+    }
+    let (cho, jung, jong, len) = decode_syllable_parts(code, contractions)?;
+    Some((build_char(cho, jung, jong), len))
+}
+
+/// 음절의 초성, 중성, 종성과 읽은 셀의 수를 반환합니다.
+fn decode_syllable_parts(
+    code: &[u8],
+    contractions: bool,
+) -> Option<(char, char, Option<char>, usize)> {
+    let first = *code.first()?;
+    if let Some(cho) = decode_choseong(first) {
+        let next = &code[1..];
+        if let Some((jung, len)) = decode_jungsong(next) {
+            if contractions && next[0] == 12 && SSANG_BATCHIM_CHOSEONG.contains(&cho) {
+                return Some((cho, 'ㅏ', Some('ㅆ'), 2));
+            }
+            let (jong, jong_len) = decode_final(&next[len..], None);
+            return Some((cho, jung, jong, 1 + len + jong_len));
+        }
+        if !contractions {
+            return None;
+        }
+        if next.first() == Some(&59) {
+            // 제17항 ‘성, 썽, 정, 쩡, 청’을 적을 때에는 ‘ㅅ, ㅆ, ㅈ, ㅉ, ㅊ’ 다음에 ‘영’의 약자 ⠻을 적어 나타낸다.
+            let jung = if ['ㅅ', 'ㅆ', 'ㅈ', 'ㅉ', 'ㅊ'].contains(&cho) {
+                'ㅓ'
+            } else {
+                'ㅕ'
+            };
+            return Some((cho, jung, Some('ㅇ'), 2));
+        }
+        if let Some((shortcut, len)) = decode_char_shortcut(next)
+            && has_choseong_o(shortcut)
+        {
+            // 13항 ‘억, 언, 얼, …’ 약자 앞에 첫소리를 적은 경우
+            let korean = KoreanChar::new(shortcut).ok()?;
+            let (jong, jong_len) = decode_final(&next[len..], korean.jong);
+            return Some((cho, korean.jung, jong, 1 + len + jong_len));
+        }
+        // 13항 ‘나, 다, 마, 바, 자, 카, 타, 파, 하’ 약자
+        decode_char_shortcut(&[first])?;
+        let (jong, jong_len) = decode_final(next, None);
+        return Some((cho, 'ㅏ', jong, 1 + jong_len));
+    }
+    if contractions && let Some((shortcut, len)) = decode_char_shortcut(code) {
+        // 13항, 15항 약자
+        let korean = KoreanChar::new(shortcut).ok()?;
+        let (jong, jong_len) = decode_final(&code[len..], korean.jong);
+        return Some((korean.cho, korean.jung, jong, len + jong_len));
+    }
+    // 1항 [다만] ‘ㅇ’이 첫소리로 쓰일 때에는 점자로 이를 표기하지 않는다.
+    let (jung, len) = decode_jungsong(code)?;
+    let (jong, jong_len) = decode_final(&code[len..], None);
+    Some(('ㅇ', jung, jong, len + jong_len))
+}
+
+/// 받침을 읽습니다. 이미 받침이 있으면 겹받침이 되는 경우에만 더 읽습니다.
+fn decode_final(code: &[u8], mut jong: Option<char>) -> (Option<char>, usize) {
+    let mut len = 0;
+    while len < code.len().min(2) {
+        let rest = &code[len..];
+        if decode_char_symbol_shortcut(rest).is_some_and(|(_, l)| l > 1)
+            || (rest.len() == 1 && [50, 22, 38].contains(&rest[0]))
+        {
+            // 받침 뒤에 붙은 괄호, 따옴표나 단어 끝의 ⠲, ⠖, ⠦ 은 문장 부호로 읽는다.
+            break;
+        }
+        let Some((next, _)) = decode_jongseong(&rest[..1]) else {
+            break;
+        };
+        let merged = match jong {
+            None => Some(next),
+            Some(prev) => merge_korean_jauem(prev, next),
+        };
+        match merged {
+            Some(merged) if !['ㄸ', 'ㅃ', 'ㅉ'].contains(&merged) => {
+                jong = Some(merged);
+                len += 1;
+            }
+            _ => break,
+        }
+    }
+    (jong, len)
+}
+
+/// 국어 문장으로 보고 점자 셀을 역점역합니다.
+pub fn decode(cells: &[u8]) -> Result<String, BrailleError> {
+    let mut decoder = Decoder::new(true);
+    let mut result = String::new();
+    decoder.decode(cells, &mut result)?;
+    Ok(result)
+}
+
+/// 풀어쓰기로 적힌 국어 문장의 점자 셀을 역점역합니다.
+pub fn decode_uncontracted(cells: &[u8]) -> Result<String, BrailleError> {
+    let mut decoder = Decoder::new(true).korean_contractions(false);
+    let mut result = String::new();
+    decoder.decode(cells, &mut result)?;
+    Ok(result)
+}
+
+pub fn decode_from_unicode(text: &str) -> Result<String, BrailleError> {
+    let cells = text
+        .char_indices()
+        .enumerate()
+        .map(|(index, (byte, c))| {
+            try_decode_unicode(c).ok_or(BrailleError::InvalidBrailleCharacter {
+                character: c,
+                offset: Offset::new(byte, index),
+            })
+        })
+        .collect::<Result<Vec<u8>, BrailleError>>()?;
+    decode(&cells)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::encode;
+
+    fn roundtrip(text: &str) -> String {
+        decode(&encode(text).unwrap()).unwrap()
+    }
+
+    #[test]
+    pub fn test_decode() {
+        assert_eq!(decode_from_unicode("⠣⠒⠉⠻⠚⠠⠝⠬").unwrap(), "안녕하세요");
+        assert_eq!(decode_from_unicode("⠣⠒⠉⠻\n⠘⠒⠫⠏").unwrap(), "안녕\n반가워");
+        assert_eq!(decode_from_unicode("⠁⠎⠀⠨⠁⠊⠿").unwrap(), "그래서 작동");
+        assert_eq!(decode_from_unicode("⠠⠕⠤⠌⠬").unwrap(), "시예요");
+        assert_eq!(decode_from_unicode("⠠⠥⠚⠧⠤⠗⠁").unwrap(), "소화액");
+        assert!(decode_from_unicode("abc").is_err());
+        assert!(decode(&[64]).is_err());
+
+        for text in [
+            "상상이상의",
+            "반가워",
+            "것이",
+            "겄",
+            "껐어요.",
+            "끊겼어요",
+            "청소를",
+            "정",
+            "나요",
+            "사이즈",
+            "안녕하십니까",
+            "그래서 작동하나요?",
+            "아름다운 세상.",
+            "모든 것이 무너진 듯해도",
+            "삼각형 ㄱㄴㄷ",
+            "‘ㅖ’로 적는다.",
+            "Roma [ㄹㄹ로마]",
+            "BMI(지수)",
+            "체질량 지수(BMI)",
+            "SNS에서",
+            "ATM 기기",
+            "TV를",
+            "1,000",
+            "0.48",
+            "02-2669-9775~6",
+            "나루 + 배 = 나룻배",
+            "그는 Canada로 여행을 떠났다.",
+        ] {
+            assert_eq!(roundtrip(text), text);
+        }
+    }
+
+    #[test]
+    pub fn test_decode_quote() {
+        for text in [
+            "\"가나다\"",
+            "‘가’ \"안녕하세요\"",
+            "\"가 나\" 다",
+            "‘\"가\"’",
+            "(\"가\")",
+            "「\"가\"」 [\"나\"]",
+            "\"1,000\"",
+            "그는 \"Hello\"라고",
+            "\"가\" 좋",
+        ] {
+            assert_eq!(roundtrip(text), text);
+        }
+        // 닫는 큰따옴표 뒤에 한글이 붙으면 받침 ‘ㅎ’ 으로 읽습니다.
+        assert_eq!(roundtrip("\"다\"고"), "\"닿고");
+    }
+
+    #[test]
+    pub fn test_decode_old_hangul() {
+        // 옛한글은 점역만 할 수 있으며 같은 점형의 현대 한글로 읽습니다.
+        assert_eq!(roundtrip("\u{1112}\u{119E}\u{11AB}글"), "하ㄹ⠒글");
+        assert!(
+            !roundtrip("\u{1140}\u{1161} ㅿ")
+                .chars()
+                .any(|c| crate::old_hangul::is_old_hangul_char(c)
+                    || crate::old_hangul::is_old_hangul_part(c))
+        );
+    }
+
+    #[test]
+    pub fn test_decode_greek() {
+        // 그리스 문자는 점역만 할 수 있으며 같은 점형의 한글로 읽습니다.
+        assert_eq!(roundtrip("α"), "작");
+        assert!(!roundtrip("αβγ ΣΩ").chars().any(crate::greek::is_greek_char));
+    }
+
+    #[test]
+    pub fn test_decode_english() {
+        let mut decoder = Decoder::new(false);
+        for text in [
+            "Table of Contents",
+            "WELCOME TO KOREA",
+            "bonjour",
+            "kg",
+            "(kg)",
+        ] {
+            let mut result = String::new();
+            decoder.decode(&encode(text).unwrap(), &mut result).unwrap();
+            assert_eq!(result, text);
+        }
+    }
+}
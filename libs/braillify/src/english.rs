@@ -1,6 +1,6 @@
 use phf::phf_map;
 
-use crate::unicode::decode_unicode;
+use crate::{error::BrailleError, unicode::decode_unicode};
 
 pub static ENGLISH_MAP: phf::Map<char, u8> = phf_map! {
     'a' => decode_unicode('⠁'),
@@ -30,10 +30,75 @@ pub static ENGLISH_MAP: phf::Map<char, u8> = phf_map! {
     'y' => decode_unicode('⠽'),
     'z' => decode_unicode('⠵'),
 };
+/// 로마자 사이에 나오는 문장 부호, ｢통일영어점자 규정｣의 부호로 적습니다.
+static PUNCTUATION_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    '.' => &[decode_unicode('⠲')],
+    ',' => &[decode_unicode('⠂')],
+    '\'' => &[decode_unicode('⠄')],
+    '’' => &[decode_unicode('⠄')],
+    '-' => &[decode_unicode('⠤')],
+    '/' => &[decode_unicode('⠸'), decode_unicode('⠌')],
+    ':' => &[decode_unicode('⠒')],
+    ';' => &[decode_unicode('⠆')],
+    '!' => &[decode_unicode('⠖')],
+    '?' => &[decode_unicode('⠦')],
+};
+
 /// 제28항 로마자는 ｢통일영어점자 규정｣에 따라 다음과 같이 적는다.
-pub fn encode_english(text: char) -> Result<u8, String> {
+pub fn encode_english(text: char) -> Result<u8, BrailleError> {
     if let Some(code) = ENGLISH_MAP.get(&text.to_ascii_lowercase()) {
         return Ok(*code);
     }
-    Err("Invalid English character".to_string())
+    Err(BrailleError::invalid_english(text))
+}
+
+pub fn encode_english_punctuation(text: char) -> Option<&'static [u8]> {
+    PUNCTUATION_MAP.get(&text).copied()
+}
+
+pub fn is_english_punctuation(text: char) -> bool {
+    PUNCTUATION_MAP.contains_key(&text)
+}
+
+/// 마침표를 찍어 줄여 쓰는 로마자 낱말 (Mr. Kim, etc.)
+const ABBREVIATIONS: [&str; 10] = [
+    "mr", "mrs", "ms", "dr", "st", "jr", "sr", "vs", "etc", "prof",
+];
+
+/// `chars[index]` 의 마침표가 약어(U.S.A., e.g., Mr.)의 마침표인지 확인합니다.
+///
+/// 한 글자 뒤의 마침표는 앞뒤에 마침표로 이어진 글자가 있을 때만 약어로 보므로, 로마자 문장 끝의
+/// 마침표는 약어가 아닙니다.
+pub fn is_abbreviation_period(chars: &[char], index: usize) -> bool {
+    let start = chars[..index]
+        .iter()
+        .rposition(|c| !c.is_ascii_alphabetic())
+        .map_or(0, |end| end + 1);
+    let letters = &chars[start..index];
+    match letters.len() {
+        0 => false,
+        1 => {
+            (start > 0 && chars[start - 1] == '.')
+                || chars
+                    .get(index + 1)
+                    .is_some_and(|c| c.is_ascii_alphabetic())
+        }
+        len => {
+            start == 0
+                && ABBREVIATIONS.iter().any(|word| {
+                    word.len() == len
+                        && letters
+                            .iter()
+                            .zip(word.chars())
+                            .all(|(c, w)| c.to_ascii_lowercase() == w)
+                })
+        }
+    }
+}
+
+pub fn decode_english(code: u8) -> Option<char> {
+    ENGLISH_MAP
+        .entries()
+        .find(|(_, value)| **value == code)
+        .map(|(key, _)| *key)
 }
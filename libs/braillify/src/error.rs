@@ -0,0 +1,261 @@
+use core::fmt;
+
+/// 입력 문자열 안의 위치
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Offset {
+    /// UTF-8 바이트 위치
+    pub byte: usize,
+    /// 문자(`char`) 위치
+    pub char: usize,
+}
+
+impl Offset {
+    pub fn new(byte: usize, char: usize) -> Self {
+        Self { byte, char }
+    }
+
+    /// `text` 의 `char_index` 번째 문자의 위치를 구합니다.
+    pub(crate) fn of_char(text: &str, char_index: usize) -> Self {
+        Self {
+            byte: text
+                .char_indices()
+                .nth(char_index)
+                .map_or(text.len(), |(byte, _)| byte),
+            char: char_index,
+        }
+    }
+
+    /// `inner` 가 `text` 의 부분 문자열일 때 `text` 기준의 시작 위치를 구합니다.
+    pub(crate) fn of_slice(text: &str, inner: &str) -> Self {
+        let byte = inner.as_ptr() as usize - text.as_ptr() as usize;
+        Self {
+            byte,
+            char: text[..byte].chars().count(),
+        }
+    }
+}
+
+impl core::ops::Add for Offset {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            byte: self.byte + rhs.byte,
+            char: self.char + rhs.char,
+        }
+    }
+}
+
+impl core::ops::Sub for Offset {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            byte: self.byte - rhs.byte,
+            char: self.char - rhs.char,
+        }
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "char {} (byte {})", self.char, self.byte)
+    }
+}
+
+/// 점역, 역점역 중 발생한 오류
+///
+/// 묵자 입력에 대한 오류는 문제가 된 문자와 원문 안의 위치를 함께 담습니다.
+/// 오류의 종류는 늘어날 수 있으므로 `match` 할 때에는 `_` 갈래를 두어야 합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BrailleError {
+    /// 점자로 옮길 수 없는 문자
+    UnsupportedCharacter { character: char, offset: Offset },
+    /// 한글 음절이 아닌 문자
+    InvalidKorean { character: char, offset: Offset },
+    /// 점자 표기가 없는 한글 자모
+    InvalidJamo { character: char, offset: Offset },
+    /// 로마자가 아닌 문자
+    InvalidEnglish { character: char, offset: Offset },
+    /// 숫자가 아닌 문자
+    InvalidNumber { character: char, offset: Offset },
+    /// 점자 표기가 없는 문장 부호, 수학 기호
+    InvalidSymbol { character: char, offset: Offset },
+    /// 6점 점자 범위(0..64)를 벗어난 셀
+    InvalidBrailleCell { cell: u8, index: usize },
+    /// 유니코드 점자(U+2800..U+283F)가 아닌 문자
+    InvalidBrailleCharacter { character: char, offset: Offset },
+    /// 점역 결과를 담기에 출력 버퍼가 작음 (`required` 는 필요한 칸 수)
+    BufferTooSmall { required: usize, capacity: usize },
+}
+
+impl BrailleError {
+    pub(crate) fn unsupported_character(character: char) -> Self {
+        Self::UnsupportedCharacter {
+            character,
+            offset: Offset::default(),
+        }
+    }
+
+    pub(crate) fn invalid_korean(character: char) -> Self {
+        Self::InvalidKorean {
+            character,
+            offset: Offset::default(),
+        }
+    }
+
+    pub(crate) fn invalid_jamo(character: char) -> Self {
+        Self::InvalidJamo {
+            character,
+            offset: Offset::default(),
+        }
+    }
+
+    pub(crate) fn invalid_english(character: char) -> Self {
+        Self::InvalidEnglish {
+            character,
+            offset: Offset::default(),
+        }
+    }
+
+    pub(crate) fn invalid_number(character: char) -> Self {
+        Self::InvalidNumber {
+            character,
+            offset: Offset::default(),
+        }
+    }
+
+    pub(crate) fn invalid_symbol(character: char) -> Self {
+        Self::InvalidSymbol {
+            character,
+            offset: Offset::default(),
+        }
+    }
+
+    /// 문제가 된 묵자 또는 유니코드 점자 문자
+    pub fn character(&self) -> Option<char> {
+        match self {
+            Self::UnsupportedCharacter { character, .. }
+            | Self::InvalidKorean { character, .. }
+            | Self::InvalidJamo { character, .. }
+            | Self::InvalidEnglish { character, .. }
+            | Self::InvalidNumber { character, .. }
+            | Self::InvalidSymbol { character, .. }
+            | Self::InvalidBrailleCharacter { character, .. } => Some(*character),
+            Self::InvalidBrailleCell { .. } | Self::BufferTooSmall { .. } => None,
+        }
+    }
+
+    /// 문제가 된 문자의 원문 안의 위치
+    pub fn offset(&self) -> Option<Offset> {
+        match self {
+            Self::UnsupportedCharacter { offset, .. }
+            | Self::InvalidKorean { offset, .. }
+            | Self::InvalidJamo { offset, .. }
+            | Self::InvalidEnglish { offset, .. }
+            | Self::InvalidNumber { offset, .. }
+            | Self::InvalidSymbol { offset, .. }
+            | Self::InvalidBrailleCharacter { offset, .. } => Some(*offset),
+            Self::InvalidBrailleCell { .. } | Self::BufferTooSmall { .. } => None,
+        }
+    }
+
+    /// 문제가 된 문자의 위치를 `f` 로 옮깁니다.
+    pub(crate) fn map_offset(mut self, f: impl FnOnce(Offset) -> Offset) -> Self {
+        match &mut self {
+            Self::UnsupportedCharacter { offset, .. }
+            | Self::InvalidKorean { offset, .. }
+            | Self::InvalidJamo { offset, .. }
+            | Self::InvalidEnglish { offset, .. }
+            | Self::InvalidNumber { offset, .. }
+            | Self::InvalidSymbol { offset, .. }
+            | Self::InvalidBrailleCharacter { offset, .. } => *offset = f(*offset),
+            Self::InvalidBrailleCell { .. } | Self::BufferTooSmall { .. } => {}
+        }
+        self
+    }
+
+    /// 하위 문자열 기준의 위치를 바깥 문자열 기준으로 옮깁니다.
+    pub(crate) fn shift(mut self, base: Offset) -> Self {
+        match &mut self {
+            Self::UnsupportedCharacter { offset, .. }
+            | Self::InvalidKorean { offset, .. }
+            | Self::InvalidJamo { offset, .. }
+            | Self::InvalidEnglish { offset, .. }
+            | Self::InvalidNumber { offset, .. }
+            | Self::InvalidSymbol { offset, .. }
+            | Self::InvalidBrailleCharacter { offset, .. } => *offset = base + *offset,
+            Self::InvalidBrailleCell { index, .. } => *index += base.char,
+            Self::BufferTooSmall { .. } => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for BrailleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedCharacter { character, offset } => {
+                write!(f, "Unsupported character {character:?} at {offset}")
+            }
+            Self::InvalidKorean { character, offset } => {
+                write!(f, "Invalid Korean character {character:?} at {offset}")
+            }
+            Self::InvalidJamo { character, offset } => {
+                write!(f, "Invalid Korean jamo {character:?} at {offset}")
+            }
+            Self::InvalidEnglish { character, offset } => {
+                write!(f, "Invalid English character {character:?} at {offset}")
+            }
+            Self::InvalidNumber { character, offset } => {
+                write!(f, "Invalid number character {character:?} at {offset}")
+            }
+            Self::InvalidSymbol { character, offset } => {
+                write!(f, "Invalid symbol character {character:?} at {offset}")
+            }
+            Self::InvalidBrailleCell { cell, index } => {
+                write!(f, "Invalid braille cell {cell} at index {index}")
+            }
+            Self::InvalidBrailleCharacter { character, offset } => {
+                write!(f, "Invalid braille character {character:?} at {offset}")
+            }
+            Self::BufferTooSmall { required, capacity } => {
+                write!(
+                    f,
+                    "Buffer too small: {required} cells required, capacity {capacity}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for BrailleError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_offset() {
+        let text = "가a나";
+        assert_eq!(Offset::of_char(text, 0), Offset::new(0, 0));
+        assert_eq!(Offset::of_char(text, 1), Offset::new(3, 1));
+        assert_eq!(Offset::of_char(text, 2), Offset::new(4, 2));
+        assert_eq!(Offset::of_char(text, 3), Offset::new(7, 3));
+        assert_eq!(Offset::of_slice(text, &text[4..]), Offset::new(4, 2));
+    }
+
+    #[test]
+    fn test_shift() {
+        let error = BrailleError::unsupported_character('😀').shift(Offset::new(3, 1));
+        assert_eq!(error.character(), Some('😀'));
+        assert_eq!(error.offset(), Some(Offset::new(3, 1)));
+        assert_eq!(
+            error.to_string(),
+            "Unsupported character '😀' at char 1 (byte 3)"
+        );
+    }
+}
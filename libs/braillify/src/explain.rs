@@ -0,0 +1,282 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// 점자 규정의 한 항 (`rule_map.json` 과 같은 ID, 제목)
+///
+/// `serde` 로는 `&'static Rule` 만 역직렬화할 수 있으며 `id` 로 `RULES` 에서 찾습니다.
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Rule {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+}
+
+/// `rule_map.json` 의 항목
+pub static RULES: &[Rule] = &[
+    Rule {
+        id: "rule_1",
+        title: "1항",
+        description: "기본 자음자 14개가 첫소리로 쓰일 때에는 다음과 같이 적는다.",
+    },
+    Rule {
+        id: "rule_1_b1",
+        title: "1항 다만",
+        description: "‘ㅇ’이 첫소리로 쓰일 때에는 점자로 이를 표기하지 않는다.",
+    },
+    Rule {
+        id: "rule_2",
+        title: "2항",
+        description: "된소리 글자 ‘ㄲ, ㄸ, ㅃ, ㅆ, ㅉ’이 첫소리로 쓰일 때에는 ‘ㄱ, ㄷ, ㅂ, ㅅ, ㅈ’ 앞에 된소리표 ⠠을 적어 나타낸다.",
+    },
+    Rule {
+        id: "rule_3",
+        title: "3항",
+        description: "기본 자음자 14개가 받침으로 쓰일 때에는 다음과 같이 적는다.",
+    },
+    Rule {
+        id: "rule_4",
+        title: "4항",
+        description: "쌍받침 ‘ㄲ’은 ⠁⠁으로 적고, 쌍받침 ‘ㅆ’은 약자인 ⠌으로 적는다.",
+    },
+    Rule {
+        id: "rule_5",
+        title: "5항",
+        description: "겹받침은 각 받침 글자를 어울러 다음과 같이 적는다.",
+    },
+    Rule {
+        id: "rule_6",
+        title: "6항",
+        description: "기본 모음자 10개는 다음과 같이 적는다.",
+    },
+    Rule {
+        id: "rule_7",
+        title: "7항",
+        description: "그 밖의 모음자 11개는 다음과 같이 적는다.",
+    },
+    Rule {
+        id: "rule_8",
+        title: "8항",
+        description: "자음자나 모음자가 단독으로 쓰일 때에는 해당 글자 앞에 온표 =을 적어 나타내며, 자음자는 받침으로 적는다.",
+    },
+    Rule {
+        id: "rule_9",
+        title: "9항",
+        description: "한글의 자음자가 번호로 쓰일 때에는 온표를 앞세워 받침으로 적는다.",
+    },
+    Rule {
+        id: "rule_10",
+        title: "10항",
+        description: "단독으로 쓰인 자음자가 단어에 붙어 나올 때에는 ⠸을 앞세워 받침으로 적는다.",
+    },
+    Rule {
+        id: "rule_11",
+        title: "11항",
+        description: "모음자에 ‘예’가 붙어 나올 때에는 그 사이에 구분표 ⠤을 적어 나타낸다.",
+    },
+    Rule {
+        id: "rule_11_b1",
+        title: "11항 다만",
+        description: "그 사이에서 줄이 바뀔 때에는 구분표를 적지 않는다.",
+    },
+    Rule {
+        id: "rule_12",
+        title: "12항",
+        description: "‘ㅑ, ㅘ, ㅜ, ㅝ’에 ‘애’가 붙어 나올 때에는 두 모음자 사이에 구분표 ⠤을 적어 나타낸다.",
+    },
+    Rule {
+        id: "rule_12_b1",
+        title: "12항 다만",
+        description: "그 사이에서 줄이 바뀔 때에는 구분표를 적지 않는다.",
+    },
+    Rule {
+        id: "rule_13",
+        title: "13항",
+        description: "다음 글자들은 약자를 사용하여 적는다.",
+    },
+    Rule {
+        id: "rule_14",
+        title: "14항",
+        description: "‘나, 다, 마, 바, 자, 카, 타, 파, 하’에 모음이 붙어 나올 때에는 약자를 사용하지 않는다.",
+    },
+    Rule {
+        id: "rule_14_b1",
+        title: "14항 다만",
+        description: "그 사이에서 줄이 바뀔 때에는 약자를 사용하여 적는다.",
+    },
+    Rule {
+        id: "rule_15",
+        title: "15항",
+        description: "다음 글자들은 약자를 사용하여 적는다.",
+    },
+    Rule {
+        id: "rule_16",
+        title: "16항",
+        description: "‘까, 싸, 껏’을 적을 때에는 ‘가, 사, 것’의 약자 앞에 된소리표를 적어 나타낸다.",
+    },
+    Rule {
+        id: "rule_17",
+        title: "17항",
+        description: "‘성, 썽, 정, 쩡, 청’을 적을 때에는 ‘ㅅ, ㅆ, ㅈ, ㅉ, ㅊ’ 다음에 ‘영’의 약자 ⠻을 적어 나타낸다.",
+    },
+    Rule {
+        id: "rule_18",
+        title: "18항",
+        description: "다음 단어들은 약어를 사용하여 적는다.",
+    },
+    Rule {
+        id: "rule_18_b1",
+        title: "18항 다만",
+        description: "약어 앞에 다른 글자가 붙어 나올 때에는 약어를 사용하지 않는다.",
+    },
+    Rule {
+        id: "old_hangul",
+        title: "옛한글",
+        description: "옛한글 음절은 약자를 사용하지 않고 첫가끝 자모를 하나씩 풀어 적으며, 옛 자모 ‘ㅿ, ㆁ, ㆆ, ㆍ’와 입술가벼운소리는 ⠸을 앞세워 적는다.",
+    },
+    Rule {
+        id: "rule_28",
+        title: "28항",
+        description: "로마자는 ｢통일영어점자 규정｣에 따라 다음과 같이 적는다.",
+    },
+    Rule {
+        id: "rule_29",
+        title: "29항",
+        description: "국어 문장 안에 로마자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다. 이때 로마자가 둘 이상 연이어 나오면 첫 로마자 앞에 로마자표를 적고 마지막 로마자 뒤에 로마자 종료표를 적는다.",
+    },
+    Rule {
+        id: "rule_30",
+        title: "30항",
+        description: "로마자 약어 안의 마침표는 ｢통일영어점자 규정｣의 마침표 ⠲로 적고, 로마자 종료표는 약어 전체의 뒤에 한 번만 적는다.",
+    },
+    Rule {
+        id: "rule_31",
+        title: "31항",
+        description: "국어 문장 안에 그리스 문자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다.",
+    },
+    Rule {
+        id: "rule_34",
+        title: "34항",
+        description: "로마자가 따옴표나 괄호 등으로 묶일 때에는 로마자 종료표를 적지 않는다.",
+    },
+    Rule {
+        id: "paired_punctuation",
+        title: "여닫는 문장 부호",
+        description: "따옴표와 괄호는 여는 것과 닫는 것을 구별하여 적는다. 큰따옴표는 ⠦ ⠴, 작은따옴표는 ⠠⠦ ⠴⠄으로 적으며, 곧은 따옴표(\", ')는 앞에 열린 따옴표가 있으면 닫는 따옴표로 본다.",
+    },
+    Rule {
+        id: "rule_40",
+        title: "40항",
+        description: "숫자는 수표 ⠼을 앞세워 다음과 같이 적는다.",
+    },
+    Rule {
+        id: "rule_41",
+        title: "41항",
+        description: "숫자 사이에 붙어 나오는 쉼표와 자릿점은 ⠂으로 적는다.",
+    },
+    Rule {
+        id: "rule_42",
+        title: "42항",
+        description: "일곱 자리 이상의 긴 숫자를 두 줄에 나누어 적을 때에는 위 줄 끝에 연결표 ⠠을 적고, 아래 줄의 첫머리에는 수표를 다시 적지 않는다. 이때 아래 줄에는 세 자리 이상의 숫자가 나와야 한다.",
+    },
+    Rule {
+        id: "rule_43",
+        title: "43항",
+        description: "숫자 사이에 마침표, 쉼표, 연결표가 붙어 나올 때에는 뒤의 숫자에 수표를",
+    },
+    Rule {
+        id: "rule_43_b1",
+        title: "43항 다만",
+        description: "그 밖의 다른 기호가 숫자 사이에 붙어 나올 때에는 수표를 다시 적는다.",
+    },
+    Rule {
+        id: "rule_44",
+        title: "44항",
+        description: "숫자 뒤에 이어 나오는 한글의 띄어쓰기는 묵자를 따른다.",
+    },
+    Rule {
+        id: "rule_44_b1",
+        title: "44항 다만",
+        description: "숫자와 혼동되는 ‘ㄴ, ㄷ, ㅁ, ㅋ, ㅌ, ㅍ, ㅎ’의 첫소리 글자와 ‘운’의 약자는 숫자 뒤에 붙어 나오더라도 숫자와 한글을 띄어 쓴다.",
+    },
+    Rule {
+        id: "rule_45",
+        title: "45항",
+        description: "연산 기호와 비교 기호는 다음과 같이 적는다.",
+    },
+    Rule {
+        id: "rule_46",
+        title: "46항",
+        description: "연산 기호와 비교 기호가 한글 사이에 나올 때에는 기호의 앞뒤를 한 칸씩 띄어 쓴다.",
+    },
+    Rule {
+        id: "sentence",
+        title: "문장",
+        description: "테스트를 위한 문장",
+    },
+];
+
+impl Rule {
+    /// ID 로 규정을 찾습니다.
+    pub fn get(id: &str) -> Option<&'static Rule> {
+        RULES.iter().find(|rule| rule.id == id)
+    }
+}
+
+/// 셀 묶음과 그 셀을 만든 글자 범위, 규정
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExplainedSpan {
+    /// 입력 문자열의 글자(`char`) 범위, 표시 기호는 빈 범위입니다. (`BrailleSpan` 참고)
+    pub source: Range<usize>,
+    /// 출력 셀 범위
+    pub cells: Range<usize>,
+    /// 셀을 만든 규정, 띄어쓰기나 문장 부호처럼 `rule_map.json` 에 없는 경우 `None`
+    pub rule: Option<&'static Rule>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for &'static Rule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct RuleId {
+            id: alloc::string::String,
+        }
+
+        let RuleId { id } = RuleId::deserialize(deserializer)?;
+        Rule::get(&id)
+            .ok_or_else(|| serde::de::Error::custom(alloc::format!("unknown rule {id:?}")))
+    }
+}
+
+/// 점역 결과와 셀 묶음별 규정
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExplainedEncoding {
+    pub cells: Vec<u8>,
+    pub spans: Vec<ExplainedSpan>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_rules_match_rule_map() {
+        let rule_map: HashMap<String, HashMap<String, String>> = serde_json::from_str(
+            &std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/../../rule_map.json"))
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(RULES.len(), rule_map.len());
+        for rule in RULES {
+            let entry = &rule_map[rule.id];
+            assert_eq!(rule.title, entry["title"]);
+            assert_eq!(rule.description, entry["description"]);
+        }
+        assert_eq!(Rule::get("rule_44_b1").unwrap().title, "44항 다만");
+        assert_eq!(Rule::get("rule_99"), None);
+    }
+}
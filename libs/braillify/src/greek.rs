@@ -0,0 +1,80 @@
+use phf::phf_map;
+
+use crate::{error::BrailleError, unicode::decode_unicode};
+
+/// 그리스 문자표
+pub const GREEK_INDICATOR: u8 = decode_unicode('⠨');
+
+/// 그리스 문자 소문자, 대문자는 소문자로 바꾸어 찾습니다.
+pub static GREEK_MAP: phf::Map<char, u8> = phf_map! {
+    'α' => decode_unicode('⠁'),
+    'β' => decode_unicode('⠃'),
+    'γ' => decode_unicode('⠛'),
+    'δ' => decode_unicode('⠙'),
+    'ε' => decode_unicode('⠑'),
+    'ζ' => decode_unicode('⠵'),
+    'η' => decode_unicode('⠱'),
+    'θ' => decode_unicode('⠹'),
+    'ι' => decode_unicode('⠊'),
+    'κ' => decode_unicode('⠅'),
+    'λ' => decode_unicode('⠇'),
+    'μ' => decode_unicode('⠍'),
+    'ν' => decode_unicode('⠝'),
+    'ξ' => decode_unicode('⠭'),
+    'ο' => decode_unicode('⠕'),
+    'π' => decode_unicode('⠏'),
+    'ρ' => decode_unicode('⠗'),
+    'σ' => decode_unicode('⠎'),
+    // 낱말 끝의 시그마
+    'ς' => decode_unicode('⠎'),
+    'τ' => decode_unicode('⠞'),
+    'υ' => decode_unicode('⠥'),
+    'φ' => decode_unicode('⠋'),
+    'χ' => decode_unicode('⠯'),
+    'ψ' => decode_unicode('⠽'),
+    'ω' => decode_unicode('⠺'),
+};
+
+fn to_lowercase(c: char) -> char {
+    match c {
+        // 대문자 Α..Ω 는 소문자 α..ω 와 0x20 차이입니다. (U+03A2 는 비어 있습니다)
+        '\u{0391}'..='\u{03A1}' | '\u{03A3}'..='\u{03A9}' => {
+            char::from_u32(c as u32 + 0x20).unwrap_or(c)
+        }
+        _ => c,
+    }
+}
+
+pub fn is_greek_char(c: char) -> bool {
+    GREEK_MAP.contains_key(&to_lowercase(c))
+}
+
+pub fn is_greek_uppercase(c: char) -> bool {
+    to_lowercase(c) != c
+}
+
+/// 그리스 문자 한 글자의 셀, 그리스 문자표와 대문자 기호표는 부르는 쪽에서 앞에 적습니다.
+pub fn encode_greek(c: char) -> Result<u8, BrailleError> {
+    if let Some(code) = GREEK_MAP.get(&to_lowercase(c)) {
+        return Ok(*code);
+    }
+    Err(BrailleError::unsupported_character(c))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_encode_greek() {
+        assert_eq!(encode_greek('α').unwrap(), 1);
+        assert_eq!(encode_greek('Ω').unwrap(), encode_greek('ω').unwrap());
+        assert_eq!(encode_greek('ς').unwrap(), encode_greek('Σ').unwrap());
+        assert!(is_greek_char('π'));
+        assert!(is_greek_uppercase('Σ'));
+        assert!(!is_greek_uppercase('ς'));
+        assert!(!is_greek_char('\u{03A2}'));
+        assert!(!is_greek_char('a'));
+        assert!(encode_greek('a').is_err());
+    }
+}
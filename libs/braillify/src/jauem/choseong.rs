@@ -1,4 +1,4 @@
-use crate::unicode::decode_unicode;
+use crate::{error::BrailleError, unicode::decode_unicode};
 // choseong map
 use phf::phf_map;
 
@@ -19,14 +19,21 @@ pub static CHOSEONG_MAP: phf::Map<char, u8> = phf_map! {
     'ㅎ' => decode_unicode('⠚'),
 };
 
-pub fn encode_choseong(text: char) -> Result<u8, String> {
+pub fn encode_choseong(text: char) -> Result<u8, BrailleError> {
     if let Some(code) = CHOSEONG_MAP.get(&text) {
         Ok(*code)
     } else {
-        Err("Invalid Korean choseong character".to_string())
+        Err(BrailleError::invalid_jamo(text))
     }
 }
 
+pub fn decode_choseong(code: u8) -> Option<char> {
+    CHOSEONG_MAP
+        .entries()
+        .find(|(_, value)| **value == code)
+        .map(|(key, _)| *key)
+}
+
 #[cfg(test)]
 mod test {
     use crate::unicode::decode_unicode;
@@ -48,4 +55,12 @@ mod test {
         assert_eq!(encode_choseong('ㅍ').unwrap(), decode_unicode('⠙'));
         assert_eq!(encode_choseong('ㅎ').unwrap(), decode_unicode('⠚'));
     }
+
+    #[test]
+    pub fn test_decode_choseong() {
+        for (key, value) in CHOSEONG_MAP.entries() {
+            assert_eq!(decode_choseong(*value), Some(*key));
+        }
+        assert_eq!(decode_choseong(decode_unicode('⠁')), None);
+    }
 }
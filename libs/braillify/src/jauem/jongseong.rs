@@ -1,4 +1,4 @@
-use crate::unicode::decode_unicode;
+use crate::{error::BrailleError, unicode::decode_unicode, utils::longest_match};
 use phf::phf_map;
 
 pub static JONGSEONG_MAP: phf::Map<char, &'static [u8]> = phf_map! {
@@ -34,16 +34,16 @@ pub static JONGSEONG_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     'ㅎ' => &[decode_unicode('⠴')],
 };
 
-pub fn encode_jongseong(text: char) -> Result<&'static [u8], String> {
+pub fn encode_jongseong(text: char) -> Result<&'static [u8], BrailleError> {
     if let Some(code) = JONGSEONG_MAP.get(&text) {
         return Ok(code);
     }
-    Err("Invalid Korean jongseong character".to_string())
+    Err(BrailleError::invalid_jamo(text))
 }
 
-// pub fn decode_jongseong(code: u8) -> char {
-//     JONGSEONG_MAP.get_by_right(&code).unwrap().clone()
-// }
+pub fn decode_jongseong(code: &[u8]) -> Option<(char, usize)> {
+    longest_match(JONGSEONG_MAP.entries().map(|(k, v)| (*k, *v)), code)
+}
 
 #[cfg(test)]
 mod test {
@@ -65,4 +65,18 @@ mod test {
         assert_eq!(encode_jongseong('ㅍ').unwrap(), vec![decode_unicode('⠲')]);
         assert_eq!(encode_jongseong('ㅎ').unwrap(), vec![decode_unicode('⠴')]);
     }
+
+    #[test]
+    pub fn test_decode_jongseong() {
+        assert_eq!(decode_jongseong(&[decode_unicode('⠁')]), Some(('ㄱ', 1)));
+        assert_eq!(
+            decode_jongseong(&[decode_unicode('⠁'), decode_unicode('⠁')]),
+            Some(('ㄲ', 2))
+        );
+        assert_eq!(
+            decode_jongseong(&[decode_unicode('⠂'), decode_unicode('⠴')]),
+            Some(('ㅀ', 2))
+        );
+        assert_eq!(decode_jongseong(&[decode_unicode('⠣')]), None);
+    }
 }
@@ -1,68 +1,133 @@
+use alloc::vec::Vec;
+
 use crate::{
     char_shortcut,
     char_struct::KoreanChar,
+    error::BrailleError,
     jauem::{choseong::encode_choseong, jongseong::encode_jongseong},
     moeum::jungsong::encode_jungsong,
     split::split_korean_jauem,
-    utils::build_char,
+    utils::{build_char, has_choseong_o},
 };
 
-pub fn encode_korean_char(korean: &KoreanChar) -> Result<Vec<u8>, String> {
-    let mut result = Vec::new();
+/// 셀 묶음마다 (규정 ID, 묶음의 끝 위치)
+pub type RuleMarks = Vec<(&'static str, usize)>;
+
+/// 점역 결과와 함께 셀 묶음을 만든 규정을 돌려줍니다.
+/// `enabled` 가 false 인 규정(13, 15, 16, 17항)의 약자는 쓰지 않고 초성, 중성, 종성을 적습니다.
+pub fn encode_korean_char(
+    korean: &KoreanChar,
+    enabled: impl Fn(&str) -> bool,
+) -> Result<(Vec<u8>, RuleMarks), BrailleError> {
     let (cho0, cho1) = split_korean_jauem(korean.cho)?;
+    let shortcut = |c: char, jong: Option<char>| {
+        // 된소리 초성을 포함한 약자(까, 싸, 껏)는 16항입니다.
+        // ‘억, 영’ 처럼 ‘ㅇ’ 으로 시작하는 약자는 초성을 따로 적으므로 해당하지 않습니다.
+        (enabled(char_shortcut_rule(korean.jung, jong))
+            && (cho1.is_none() || has_choseong_o(c) || enabled("rule_16")))
+        .then(|| char_shortcut::encode_char_shortcut(c).ok())
+        .flatten()
+    };
+    let mut result = Vec::new();
+    let mut rules = Vec::new();
     if cho1.is_some() {
         // 쌍자음이라는 뜻, 초성은 반드시 쌍자음이다.
         result.push(32);
+        rules.push(("rule_2", result.len()));
     }
     if let Some(jong) = korean.jong {
         let (jong0, jong1) = split_korean_jauem(jong)?;
-        if let Ok(code) =
-            char_shortcut::encode_char_shortcut(build_char('ㅇ', korean.jung, Some(jong0)))
-        {
+        if let Some(code) = shortcut(build_char('ㅇ', korean.jung, Some(jong0)), Some(jong0)) {
             // 초성 자체를 결합
 
             if cho0 != 'ㅇ' {
                 result.push(encode_choseong(cho0)?);
+                rules.push(("rule_1", result.len()));
             }
             result.extend(code);
+            rules.push((char_shortcut_rule(korean.jung, Some(jong0)), result.len()));
             if let Some(code) = jong1 {
                 // 이미 합쳐질 경우 종성을 더 추가한다.
                 result.extend(encode_jongseong(code)?);
+                rules.push((jongseong_rule(jong), result.len()));
             }
-        } else if let Ok(code) =
-            char_shortcut::encode_char_shortcut(build_char(cho0, korean.jung, Some(jong0)))
+        } else if let Some(code) = shortcut(build_char(cho0, korean.jung, Some(jong0)), Some(jong0))
         {
             result.extend(code);
+            rules.push((char_shortcut_rule(korean.jung, Some(jong0)), result.len()));
             if let Some(code) = jong1 {
                 // 이미 합쳐질 경우 종성을 더 추가한다.
                 result.extend(encode_jongseong(code)?);
+                rules.push((jongseong_rule(jong), result.len()));
             }
-        } else if let Ok(code) =
-            char_shortcut::encode_char_shortcut(build_char(cho0, korean.jung, None))
-        {
+        } else if let Some(code) = shortcut(build_char(cho0, korean.jung, None), None) {
             result.extend(code);
+            rules.push((char_shortcut_rule(korean.jung, None), result.len()));
             // 종성 자체를 결합
             result.extend(encode_jongseong(jong)?);
+            rules.push((jongseong_rule(jong), result.len()));
         } else {
             // shortcut 이 없으므로 초성, 중성, 종성 모두 결합
 
             if cho0 != 'ㅇ' {
                 result.push(encode_choseong(cho0)?);
+                rules.push(("rule_1", result.len()));
             }
             result.extend(encode_jungsong(korean.jung)?);
+            rules.push((jungsong_rule(korean.jung), result.len()));
             result.extend(encode_jongseong(jong)?);
+            rules.push((jongseong_rule(jong), result.len()));
         }
     } else {
-        if let Ok(code) = char_shortcut::encode_char_shortcut(build_char(cho0, korean.jung, None)) {
+        if let Some(code) = shortcut(build_char(cho0, korean.jung, None), None) {
             result.extend(code);
+            rules.push((char_shortcut_rule(korean.jung, None), result.len()));
         } else {
             // shortcut 이 없으므로 초성 중성, 모두 결합
             if cho0 != 'ㅇ' {
                 result.push(encode_choseong(cho0)?);
+                rules.push(("rule_1", result.len()));
             }
             result.extend(encode_jungsong(korean.jung)?);
+            rules.push((jungsong_rule(korean.jung), result.len()));
         }
     }
 
-    Ok(result)
+    if let [("rule_2", _), (next, _), ..] = rules[..]
+        && ["rule_13", "rule_15"].contains(&next)
+    {
+        // 16항 ‘까, 싸, 껏’을 적을 때에는 ‘가, 사, 것’의 약자 앞에 된소리표를 적어 나타낸다.
+        rules[0].0 = "rule_16";
+    }
+
+    Ok((result, rules))
+}
+
+/// 약자를 정한 규정, 받침 없는 약자는 13항, ‘성, 정, 청’은 17항, 나머지는 15항입니다.
+fn char_shortcut_rule(jung: char, jong: Option<char>) -> &'static str {
+    match (jung, jong) {
+        (_, None) => "rule_13",
+        ('ㅓ', Some('ㅇ')) => "rule_17",
+        _ => "rule_15",
+    }
+}
+
+/// 기본 모음자는 6항, 그 밖의 모음자는 7항입니다.
+fn jungsong_rule(jung: char) -> &'static str {
+    if ['ㅏ', 'ㅑ', 'ㅓ', 'ㅕ', 'ㅗ', 'ㅛ', 'ㅜ', 'ㅠ', 'ㅡ', 'ㅣ'].contains(&jung) {
+        "rule_6"
+    } else {
+        "rule_7"
+    }
+}
+
+/// 기본 자음자 받침은 3항, 쌍받침은 4항, 겹받침은 5항입니다.
+fn jongseong_rule(jong: char) -> &'static str {
+    match jong {
+        'ㄲ' | 'ㅆ' => "rule_4",
+        'ㄳ' | 'ㄵ' | 'ㄶ' | 'ㄺ' | 'ㄻ' | 'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㄿ' | 'ㅀ' | 'ㅄ' => {
+            "rule_5"
+        }
+        _ => "rule_3",
+    }
 }
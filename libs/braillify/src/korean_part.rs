@@ -1,6 +1,12 @@
 use phf::phf_map;
 
-use crate::{moeum::jungsong::JUNGSEONG_MAP, unicode::decode_unicode};
+use crate::{
+    error::BrailleError,
+    moeum::jungsong::{JUNGSEONG_MAP, decode_jungsong},
+    old_hangul::encode_old_hangul_part,
+    unicode::decode_unicode,
+    utils::longest_match,
+};
 
 pub static KOREAN_PART_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     'ㄱ' => &[decode_unicode('⠁')],
@@ -36,12 +42,17 @@ pub static KOREAN_PART_MAP: phf::Map<char, &'static [u8]> = phf_map! {
 };
 
 /// 제8항 자음자나 모음자가 단독으로 쓰일 때에는 해당 글자 앞에 온표 =을 적어 나타내며, 자음자는 받침으로 적는다
-pub fn encode_korean_part(text: char) -> Result<&'static [u8], String> {
+pub fn encode_korean_part(text: char) -> Result<&'static [u8], BrailleError> {
     if let Some(code) = KOREAN_PART_MAP.get(&text) {
         return Ok(code);
     }
     if let Some(code) = JUNGSEONG_MAP.get(&text) {
         return Ok(code);
     }
-    Err("Invalid Korean part character".to_string())
+    encode_old_hangul_part(text).ok_or(BrailleError::invalid_jamo(text))
+}
+
+pub fn decode_korean_part(code: &[u8]) -> Option<(char, usize)> {
+    longest_match(KOREAN_PART_MAP.entries().map(|(k, v)| (*k, *v)), code)
+        .or_else(|| decode_jungsong(code))
 }
@@ -0,0 +1,444 @@
+use crate::{number::NUMBER_MAP, pairing::encode_quote, span::SpannedEncoding};
+use alloc::{string::ToString, vec::Vec};
+
+/// 줄 끝에서 단어를 나누어 적을 때 앞줄 끝에 적는 연결표 ⠤
+const CONTINUATION: u8 = 36;
+/// 숫자 중간에서 줄을 바꿀 때 앞줄 끝에 적는 ⠠ (42항)
+const NUMBER_CONTINUATION: u8 = 32;
+/// 수표 ⠼
+const NUMBER_INDICATOR: u8 = 60;
+/// 두 줄에 나누어 적을 수 있는 숫자의 최소 자릿수 (42항)
+const MIN_SPLIT_DIGITS: usize = 7;
+/// 숫자 중간에서 줄을 바꿀 때 다음 줄에 적는 최소 자릿수
+const MIN_CONTINUED_DIGITS: usize = 3;
+/// 쪽 번호 앞에 비우는 최소 칸 수
+const PAGE_NUMBER_GAP: usize = 3;
+
+/// 줄 나누기, 쪽 나누기 설정
+///
+/// ```
+/// use braillify::{LayoutOptions, paginate, encode_with_spans};
+///
+/// let options = LayoutOptions::new().line_width(10).lines_per_page(2);
+/// let pages = paginate(&encode_with_spans("안녕하세요 반갑습니다").unwrap(), &options);
+/// assert_eq!(pages.len(), 2);
+/// // 쪽 번호는 각 쪽 첫 줄 오른쪽 끝에 수표와 함께 적습니다.
+/// assert_eq!(pages[1].lines[0][8..], [60, 3]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct LayoutOptions {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "at_least::<_, 2>"))]
+    pub(crate) line_width: usize,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "at_least::<_, 1>"))]
+    pub(crate) lines_per_page: usize,
+    pub(crate) page_numbers: bool,
+    pub(crate) first_page_number: usize,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            line_width: 32,
+            lines_per_page: 25,
+            page_numbers: true,
+            first_page_number: 1,
+        }
+    }
+}
+
+impl LayoutOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 한 줄의 칸 수 (기본값 32)
+    pub fn line_width(mut self, cells: usize) -> Self {
+        self.line_width = cells.max(2);
+        self
+    }
+
+    /// 한 쪽의 줄 수 (기본값 25)
+    pub fn lines_per_page(mut self, lines: usize) -> Self {
+        self.lines_per_page = lines.max(1);
+        self
+    }
+
+    /// 각 쪽 첫 줄 오른쪽 끝에 점자 쪽 번호를 적을지 여부 (기본값 true)
+    ///
+    /// 줄이 좁아 쪽 번호를 적으면 줄의 칸 수를 넘는 쪽에는 쪽 번호를 적지 않습니다.
+    pub fn page_numbers(mut self, enabled: bool) -> Self {
+        self.page_numbers = enabled;
+        self
+    }
+
+    /// 첫 쪽의 번호 (기본값 1)
+    pub fn first_page_number(mut self, number: usize) -> Self {
+        self.first_page_number = number;
+        self
+    }
+}
+
+/// 빌더와 같이 `MIN` 보다 작은 값은 `MIN` 으로 읽습니다.
+#[cfg(feature = "serde")]
+pub(crate) fn at_least<'de, D: serde::Deserializer<'de>, const MIN: usize>(
+    deserializer: D,
+) -> Result<usize, D::Error> {
+    <usize as serde::Deserialize>::deserialize(deserializer).map(|value| value.max(MIN))
+}
+
+/// 점자 한 쪽
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Page {
+    /// 쪽 번호
+    pub number: usize,
+    /// 줄마다의 셀, 쪽 번호가 있으면 첫 줄에 포함됩니다.
+    pub lines: Vec<Vec<u8>>,
+}
+
+/// 셀을 단어 경계에서 줄 단위로 나눕니다.
+struct LineBreaker<'a> {
+    cells: &'a [u8],
+    /// 단어 안에서 줄을 나눌 수 있는 셀 위치 (오름차순)
+    boundaries: Vec<usize>,
+    /// 아직 줄에 담지 않은 첫 셀의 위치, 모두 담았으면 `None`
+    start: Option<usize>,
+}
+
+impl<'a> LineBreaker<'a> {
+    fn new(encoding: &'a SpannedEncoding) -> Self {
+        // 마지막 줄바꿈 뒤에는 빈 줄을 만들지 않습니다.
+        let cells = &encoding.cells;
+        let cells = cells.strip_suffix(&[255]).unwrap_or(cells);
+        // 글자 범위가 없으면 셀마다 나눌 수 있는 것으로 봅니다.
+        let boundaries = if encoding.spans.is_empty() {
+            (1..cells.len()).collect()
+        } else {
+            encoding
+                .spans
+                .windows(2)
+                .filter(|pair| {
+                    let (before, after) = (&pair[0], &pair[1]);
+                    // 수표, 로마자표, 로마자 종료표처럼 글자에 속하지 않는 셀과 여는 따옴표는
+                    // 이웃한 글자와 떼지 않으며, 닫는 따옴표는 앞 글자와 떼지 않습니다.
+                    !before.source.is_empty()
+                        && !after.source.is_empty()
+                        && !is_quote(&encoding.cells[before.cells.clone()], true)
+                        && !is_quote(&encoding.cells[after.cells.clone()], false)
+                })
+                .map(|pair| pair[1].cells.start)
+                .collect()
+        };
+        Self {
+            cells,
+            boundaries,
+            start: Some(0),
+        }
+    }
+
+    /// `width` 칸 이하의 다음 줄을 만듭니다.
+    ///
+    /// 빈칸(0)에서 줄을 나누며, 한 줄보다 긴 단어는 글자 사이에서 나누어 앞줄 끝에 연결표 ⠤ 를
+    /// 적고 나머지를 다음 줄에 이어 적습니다.
+    fn next_line(&mut self, width: usize) -> Option<Vec<u8>> {
+        let start = self.start?;
+        let paragraph_end = self.cells[start..]
+            .iter()
+            .position(|cell| *cell == 255)
+            .map_or(self.cells.len(), |end| start + end);
+        let rest = &self.cells[start..paragraph_end];
+        let (line, consumed) = if rest.len() <= width {
+            (rest.to_vec(), rest.len())
+        } else if let Some(end) = rest[..=width].iter().rposition(|cell| *cell == 0)
+            && rest[..end].iter().any(|cell| *cell != 0)
+        {
+            let next = end + (rest[end..].len() - trim_start(&rest[end..]).len());
+            (trim_end(&rest[..end]).to_vec(), next)
+        } else {
+            let (end, mark) = self.split_word(start, width - 1);
+            let mut line = rest[..end].to_vec();
+            line.push(mark);
+            (line, end)
+        };
+        self.start = if consumed < rest.len() {
+            Some(start + consumed)
+        } else if paragraph_end < self.cells.len() {
+            Some(paragraph_end + 1)
+        } else {
+            None
+        };
+        Some(line)
+    }
+
+    /// `start` 부터 `max` 칸 이하를 앞줄에 남길 위치와 앞줄 끝에 적을 표를 정합니다.
+    fn split_word(&self, start: usize, max: usize) -> (usize, u8) {
+        let cells = self.cells;
+        let end = self
+            .boundaries
+            .iter()
+            .rev()
+            .map(|boundary| boundary.wrapping_sub(start))
+            .find(|end| (1..=max).contains(end))
+            // 한 글자가 줄보다 길면 글자 안에서라도 나눕니다.
+            .unwrap_or(max);
+        let split = start + end;
+        let first_digit = cells[..split]
+            .iter()
+            .rposition(|cell| !is_digit(*cell))
+            .map_or(0, |end| end + 1);
+        if first_digit == 0
+            || cells[first_digit - 1] != NUMBER_INDICATOR
+            || !cells.get(split).copied().is_some_and(is_digit)
+        {
+            return (end, CONTINUATION);
+        }
+        let indicator = first_digit - 1;
+        let digits = cells[first_digit..]
+            .iter()
+            .take_while(|cell| is_digit(**cell))
+            .count();
+        // 일곱 자리보다 짧은 숫자는 나누지 않고 수표부터 다음 줄에 적습니다.
+        if digits < MIN_SPLIT_DIGITS && indicator > start {
+            return (indicator - start, CONTINUATION);
+        }
+        // 42항 일곱 자리 이상의 숫자를 두 줄에 나누어 적을 때에는 앞줄 끝에 ⠠ 을 적고
+        // 다음 줄에 수표 없이 세 자리 이상 이어 적습니다.
+        let after = cells[split..]
+            .iter()
+            .take_while(|cell| is_digit(**cell))
+            .count();
+        let split = split.saturating_sub(MIN_CONTINUED_DIGITS.saturating_sub(after));
+        if split > first_digit && split > start {
+            (split - start, NUMBER_CONTINUATION)
+        } else if indicator > start {
+            // 앞줄에 숫자를 남길 수 없으면 수표 앞에서 나눕니다.
+            (indicator - start, CONTINUATION)
+        } else {
+            (end, NUMBER_CONTINUATION)
+        }
+    }
+}
+
+/// 곧은 따옴표의 여는 점형(`open`) 또는 닫는 점형인지 확인합니다.
+fn is_quote(cells: &[u8], open: bool) -> bool {
+    ['"', '\'']
+        .into_iter()
+        .any(|c| encode_quote(c, open) == Some(cells))
+}
+
+fn is_digit(cell: u8) -> bool {
+    NUMBER_MAP.values().any(|digit| *digit == cell)
+}
+
+fn trim_start(cells: &[u8]) -> &[u8] {
+    let start = cells
+        .iter()
+        .position(|cell| *cell != 0)
+        .unwrap_or(cells.len());
+    &cells[start..]
+}
+
+fn trim_end(cells: &[u8]) -> &[u8] {
+    let end = cells
+        .iter()
+        .rposition(|cell| *cell != 0)
+        .map_or(0, |end| end + 1);
+    &cells[..end]
+}
+
+/// 점자 쪽 번호, 수표 ⠼ 뒤에 숫자를 적습니다.
+fn page_number_cells(number: usize) -> Vec<u8> {
+    core::iter::once(60)
+        .chain(number.to_string().chars().map(|c| NUMBER_MAP[&c]))
+        .collect()
+}
+
+/// 점역한 셀을 `width` 칸 이하의 줄로 나눕니다. 줄바꿈(255)에서는 항상 줄을 바꿉니다.
+///
+/// 단어 안에서는 `encode_with_spans` 가 돌려준 글자 사이에서만 나누며, 글자 범위가 없으면
+/// (`SpannedEncoding::from(cells)`) 어느 셀 사이에서나 나눕니다.
+pub fn wrap_lines(encoding: &SpannedEncoding, width: usize) -> Vec<Vec<u8>> {
+    let mut breaker = LineBreaker::new(encoding);
+    core::iter::from_fn(|| breaker.next_line(width.max(2))).collect()
+}
+
+/// 점역한 셀을 줄과 쪽으로 나눕니다. 줄은 `wrap_lines` 와 같이 나눕니다.
+pub fn paginate(encoding: &SpannedEncoding, options: &LayoutOptions) -> Vec<Page> {
+    let mut breaker = LineBreaker::new(encoding);
+    let mut pages = Vec::new();
+    loop {
+        let number = options.first_page_number + pages.len();
+        let mut lines = Vec::with_capacity(options.lines_per_page);
+        for row in 0..options.lines_per_page {
+            // 쪽 번호를 적고도 본문을 두 칸 이상 적을 수 없는 좁은 줄에는 쪽 번호를 적지 않습니다.
+            let page_number = (row == 0 && options.page_numbers)
+                .then(|| page_number_cells(number))
+                .filter(|page_number| {
+                    page_number.len() + PAGE_NUMBER_GAP + 2 <= options.line_width
+                });
+            let width = match &page_number {
+                Some(page_number) => options.line_width - page_number.len() - PAGE_NUMBER_GAP,
+                None => options.line_width.max(2),
+            };
+            let Some(mut line) = breaker.next_line(width) else {
+                break;
+            };
+            if let Some(page_number) = page_number {
+                line.resize(options.line_width - page_number.len(), 0);
+                line.extend(page_number);
+            }
+            lines.push(line);
+        }
+        if lines.is_empty() {
+            break;
+        }
+        pages.push(Page { number, lines });
+    }
+    pages
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::encode_with_spans;
+
+    /// 글자 범위 없이 셀만 담은 점역 결과
+    fn unspanned(cells: &[u8]) -> SpannedEncoding {
+        cells.to_vec().into()
+    }
+
+    fn wrap_text(text: &str, width: usize) -> (Vec<u8>, Vec<Vec<u8>>) {
+        let encoding = encode_with_spans(text).unwrap();
+        let lines = wrap_lines(&encoding, width);
+        (encoding.cells, lines)
+    }
+
+    #[test]
+    pub fn test_wrap_lines() {
+        assert_eq!(wrap_lines(&unspanned(&[]), 4), vec![Vec::<u8>::new()]);
+        assert_eq!(wrap_lines(&unspanned(&[1, 2, 255]), 4), vec![vec![1, 2]]);
+        assert_eq!(
+            wrap_lines(&unspanned(&[1, 255, 255, 2]), 4),
+            vec![vec![1], vec![], vec![2]]
+        );
+        // 단어 경계에서 나누고 줄 앞뒤의 빈칸은 남기지 않습니다.
+        assert_eq!(
+            wrap_lines(&unspanned(&[1, 2, 0, 3, 4, 0, 5, 6, 7, 8]), 5),
+            vec![vec![1, 2, 0, 3, 4], vec![5, 6, 7, 8]]
+        );
+        assert_eq!(
+            wrap_lines(&unspanned(&[1, 2, 0, 3, 4, 5]), 4),
+            vec![vec![1, 2], vec![3, 4, 5]]
+        );
+        assert_eq!(
+            wrap_lines(&unspanned(&[1, 2, 3, 4, 0, 5]), 4),
+            vec![vec![1, 2, 3, 4], vec![5]]
+        );
+        // 한 줄보다 긴 단어는 연결표 ⠤ 로 이어 적습니다.
+        assert_eq!(
+            wrap_lines(&unspanned(&[1, 2, 3, 4, 5, 6, 7]), 3),
+            vec![vec![1, 2, 36], vec![3, 4, 36], vec![5, 6, 7]]
+        );
+        assert_eq!(
+            wrap_lines(&unspanned(&[9, 0, 1, 2, 3, 4]), 3),
+            vec![vec![9], vec![1, 2, 36], vec![3, 4]]
+        );
+        // 된소리표처럼 글자를 이루는 셀을 앞줄 끝에 떼어 두지 않습니다.
+        let (cells, lines) = wrap_text("까까까까까까", 4);
+        assert_eq!(
+            lines,
+            [&cells[..2], &cells[2..4], &cells[4..6], &cells[6..8]]
+                .map(|line| [line, &[36]].concat())
+                .into_iter()
+                .chain([cells[8..].to_vec()])
+                .collect::<Vec<_>>()
+        );
+        // 여는 따옴표 뒤와 닫는 따옴표 앞에서는 나누지 않습니다.
+        let (cells, lines) = wrap_text("\"가나다\"라", 5);
+        assert_eq!(
+            lines,
+            vec![[&cells[..3], &[36]].concat(), cells[3..].to_vec()]
+        );
+    }
+
+    #[test]
+    pub fn test_wrap_number() {
+        // 42항 일곱 자리 이상의 숫자는 앞줄 끝에 ⠠ 을 적고 다음 줄에 수표 없이 세 자리 이상 이어 적습니다.
+        let (cells, lines) = wrap_text("12345678901234", 6);
+        assert_eq!(
+            lines,
+            vec![
+                [&cells[..5], &[32]].concat(),
+                [&cells[5..10], &[32]].concat(),
+                cells[10..].to_vec(),
+            ]
+        );
+        let (cells, lines) = wrap_text("1234567", 6);
+        assert_eq!(
+            lines,
+            vec![[&cells[..5], &[32]].concat(), cells[5..].to_vec()]
+        );
+        // 일곱 자리보다 짧은 숫자는 나누지 않고 수표부터 다음 줄에 적습니다.
+        let (cells, lines) = wrap_text("가123456", 7);
+        assert_eq!(lines, vec![vec![cells[0], 36], cells[1..].to_vec()]);
+        assert_eq!(
+            wrap_text("가1234", 5).1,
+            vec![vec![43, 36], vec![60, 1, 3, 9, 25]]
+        );
+    }
+
+    #[test]
+    pub fn test_paginate() {
+        let cells = [1, 0, 2, 0, 3, 255, 4, 5, 6, 7, 8, 9];
+        let options = LayoutOptions::new().line_width(8).lines_per_page(2);
+        assert_eq!(
+            paginate(&unspanned(&cells), &options),
+            vec![
+                Page {
+                    number: 1,
+                    lines: vec![vec![1, 0, 2, 0, 0, 0, 60, 1], vec![3]],
+                },
+                Page {
+                    number: 2,
+                    lines: vec![vec![4, 5, 36, 0, 0, 0, 60, 3], vec![6, 7, 8, 9]],
+                },
+            ]
+        );
+        assert_eq!(
+            paginate(&unspanned(&cells), &options.clone().page_numbers(false)),
+            vec![Page {
+                number: 1,
+                lines: vec![vec![1, 0, 2, 0, 3], vec![4, 5, 6, 7, 8, 9]],
+            }]
+        );
+        assert_eq!(
+            paginate(&unspanned(&[]), &options.first_page_number(12))[0].lines,
+            vec![vec![0, 0, 0, 0, 0, 60, 1, 3]]
+        );
+    }
+
+    #[test]
+    pub fn test_paginate_narrow() {
+        // 쪽 번호를 적을 자리가 없으면 쪽 번호 없이 줄의 칸 수를 지킵니다.
+        let encoding = encode_with_spans("안녕하세요 반갑습니다").unwrap();
+        for width in 2..10 {
+            let options = LayoutOptions::new().line_width(width).lines_per_page(3);
+            for page in paginate(&encoding, &options) {
+                assert!(page.lines.iter().all(|line| line.len() <= width));
+            }
+        }
+        let pages = paginate(&unspanned(&[1, 2, 3]), &LayoutOptions::new().line_width(3));
+        assert_eq!(pages[0].lines, vec![vec![1, 2, 3]]);
+    }
+
+    #[test]
+    pub fn test_page_number_cells() {
+        assert_eq!(page_number_cells(1), vec![60, 1]);
+        assert_eq!(page_number_cells(120), vec![60, 1, 3, 26]);
+    }
+}
@@ -1,96 +1,440 @@
-use jauem::choseong::encode_choseong;
-use moeum::jungsong::encode_jungsong;
-use utils::has_choseong_o;
+//! 한국어 점역 라이브러리
+//!
+//! # 기능
+//!
+//! - `std` (기본값): `BrailleWriter`, `EncodeReader` 처럼 `std::io` 를 쓰는 기능을 켭니다.
+//! - `rayon`: `encode_batch`, `encode_document` 가 글이나 문단마다 여러 스레드에서 점역합니다.
+//! - `serde`: 셀, 범위, 오류 같은 결과와 옵션을 `Serialize`, `Deserialize` 합니다.
+//!
+//! `std` 를 끄면 `#![no_std]` + `alloc` 으로 빌드되어 표준 라이브러리가 없는 점자 정보 단말기에서도
+//! `encode`, `Encoder`, `decode` 와 출력 형식 변환을 쓸 수 있습니다. 할당자와 포인터 크기의
+//! 원자적 연산(`Arc`)이 있는 타깃이어야 합니다.
+//!
+//! 표준 라이브러리가 없는 타깃으로 직접 빌드해 `std` 에 기대는 코드가 없는지 확인할 수 있습니다.
+//!
+//! ```sh
+//! rustup target add thumbv7em-none-eabihf
+//! cargo build -p braillify --no-default-features --target thumbv7em-none-eabihf
+//! # 테스트도 `std` 없이 돌려 봅니다.
+//! cargo test -p braillify --no-default-features --lib
+//! ```
+//!
+//! # 점역 범위
+//!
+//! 로마자 장은 28~31항과 34항을 구현했습니다. 로마자 사이의 어퍼스트로피, 붙임표, 쉼표 등은
+//! 28항대로 ｢통일영어점자 규정｣의 부호로 적고 로마자 종료표는 29항대로 로마자가 끝난 뒤에 적습니다.
+//! 32, 33, 35~39항은 따로 구현하지 않았으므로 `rule_map.json` 과 `explain` 에도 없습니다.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::ops::Range;
 
 use crate::{
     char_struct::CharType,
-    jauem::jongseong::encode_jongseong,
     korean_char::encode_korean_char,
-    rule::{rule_11, rule_12},
+    pairing::Pairing,
+    rule::RuleSet,
     rule_en::{rule_en_10_4, rule_en_10_6},
-    split::split_korean_jauem,
+    sink::SliceSink,
+    span::SpanRecorder,
+    tokenizer::TokenizedWord,
 };
 
+mod ascii;
+mod batch;
+mod cell;
 mod char_shortcut;
 mod char_struct;
+mod decode;
 mod english;
+mod error;
+mod explain;
+mod greek;
 mod jauem;
 mod korean_char;
 mod korean_part;
+mod layout;
 mod math_symbol_shortcut;
 mod moeum;
+mod normalize;
 mod number;
+mod old_hangul;
+mod options;
+mod pairing;
+mod pef;
 mod rule;
 mod rule_en;
+mod sink;
+mod span;
 mod split;
+#[cfg(feature = "std")]
+mod stream;
+mod syllable;
 mod symbol_shortcut;
+mod tokenizer;
 mod unicode;
 mod utils;
 mod word_shortcut;
 
+pub use batch::{
+    encode_batch, encode_batch_with_options, encode_document, encode_document_with_options,
+};
+pub use cell::{BrailleCell, BrailleToken};
+pub use decode::{Decoder, decode, decode_from_unicode, decode_uncontracted};
+pub use error::{BrailleError, Offset};
+pub use explain::{ExplainedEncoding, ExplainedSpan, RULES, Rule};
+pub use layout::{LayoutOptions, Page, paginate, wrap_lines};
+pub use options::{Context, EncodeOptions, Newline, Unsupported};
+pub use pairing::{Diagnostic, diagnose};
+pub use pef::{PefOptions, to_pef};
+pub use rule::{KoreanRule, RulePhase, SyllableContext};
+pub use sink::{AsciiSink, BrailleSink, DotSink, UnicodeSink};
+pub use span::{BrailleSpan, SpannedEncoding};
+#[cfg(feature = "std")]
+pub use stream::{BrailleWriter, EncodeReader};
+
+/// 단어를 점역할 때 미리 살피는 뒤 단어 수 (28항 대문자 구절표는 뒤의 두 단어를 봅니다)
+pub(crate) const LOOKAHEAD: usize = 2;
+
+/// 점역할 단어인지 확인합니다. 빈 단어와 `Unsupported::Skip` 으로 모두 건너뛸 단어는 제외합니다.
+fn keeps_word(word: &str, unsupported: Unsupported) -> bool {
+    !word.is_empty()
+        && (unsupported != Unsupported::Skip || word.chars().any(|c| CharType::new(c).is_ok()))
+}
+
+/// 로마자표 ⠴ 와 로마자 종료표 ⠲ 사이에 적는 글자 (제29항 로마자, 제31항 그리스 문자)
+fn is_roman_letter(c: char) -> bool {
+    c.is_ascii_alphabetic() || greek::is_greek_char(c)
+}
+
+/// 문장 부호 뒤에 로마자가 이어지는지 확인합니다. 단어가 문장 부호로 끝나면 다음 단어를 봅니다.
+///
+/// 그리스 문자 사이의 문장 부호는 국어 문장 부호로 적으므로 그리스 문자는 보지 않습니다.
+fn continues_roman(rest: &[char], remaining_words: &[&str]) -> bool {
+    match rest.iter().find(|c| !english::is_english_punctuation(**c)) {
+        Some(c) => c.is_ascii_alphabetic(),
+        None => remaining_words
+            .first()
+            .and_then(|word| word.chars().next())
+            .is_some_and(|c| c.is_ascii_alphabetic()),
+    }
+}
+
 pub struct Encoder {
     is_english: bool,
     triple_big_english: bool,
     english_indicator: bool,
     has_processed_word: bool,
+    /// 앞서 점역한 입력이 단어 중간에서 끝나 다음 입력의 첫 단어가 그 단어에 이어지는지 여부
+    continues_word: bool,
+    spans: Option<SpanRecorder>,
+    /// 열려 있는 따옴표와 괄호
+    pairing: Pairing,
+    /// 짝이 맞지 않는 문장 부호
+    diagnostics: Vec<Diagnostic>,
+    /// 앞서 점역한 입력의 길이
+    consumed: Offset,
+    /// 지금 점역 중인 문자열의 시작 위치 (약어 뒤 나머지를 점역할 때 달라집니다)
+    text_offset: Offset,
+    /// 지금 점역 중인 단어의 시작 위치
+    word_offset: Offset,
+    options: EncodeOptions,
+    /// `BrailleSink` 로 보낸 셀 수
+    written: usize,
+    rules: RuleSet,
 }
 
 impl Encoder {
     pub fn new(english_indicator: bool) -> Self {
+        Self::with_options(EncodeOptions::new().context(if english_indicator {
+            Context::Korean
+        } else {
+            Context::English
+        }))
+    }
+
+    /// `Context::Auto` 는 문장 전체를 미리 볼 수 없으므로 `Context::Korean` 으로 봅니다.
+    pub fn with_options(options: EncodeOptions) -> Self {
         Self {
-            english_indicator,
+            english_indicator: options.context != Context::English,
             is_english: false,
             triple_big_english: false,
             has_processed_word: false,
+            continues_word: false,
+            spans: None,
+            pairing: Pairing::default(),
+            diagnostics: Vec::new(),
+            consumed: Offset::default(),
+            text_offset: Offset::default(),
+            word_offset: Offset::default(),
+            rules: options.rule_set(),
+            options,
+            written: 0,
+        }
+    }
+
+    /// 단어의 `chars` 번째 글자들이 만든 셀의 범위를 기록합니다.
+    /// `chars` 가 빈 범위이면 특정 글자에 속하지 않는 표시 기호입니다.
+    fn mark(&mut self, chars: Range<usize>, result: &[u8]) {
+        if let Some(spans) = &mut self.spans {
+            spans.record(chars, None, result.len());
         }
     }
 
-    pub fn encode(&mut self, text: &str, result: &mut Vec<u8>) -> Result<(), String> {
-        let words = text
+    /// `mark` 와 같으며 셀을 만든 규정(`rule_map.json` 의 ID)을 함께 기록합니다.
+    fn mark_rule(&mut self, chars: Range<usize>, rule: &'static str, result: &[u8]) {
+        if let Some(spans) = &mut self.spans {
+            spans.record(chars, Some(rule), result.len());
+        }
+    }
+
+    /// 단어 사이에 이어지는 상태(로마자, 대문자 구절, 열린 따옴표)가 없는지 확인합니다.
+    pub(crate) fn is_clear(&self) -> bool {
+        !self.is_english && !self.triple_big_english && self.pairing.is_empty()
+    }
+
+    /// 지금까지 찾은 짝이 맞지 않는 따옴표와 괄호, 위치는 처음 넘긴 입력부터 센 원문 기준입니다.
+    ///
+    /// 끝까지 닫히지 않은 부호는 `finish` 를 부른 뒤에 알립니다.
+    ///
+    /// ```
+    /// use braillify::{Diagnostic, Encoder, Offset};
+    ///
+    /// let mut encoder = Encoder::new(true);
+    /// let mut cells = Vec::new();
+    /// encoder.encode("(가", &mut cells).unwrap();
+    /// encoder.encode("나) 다)", &mut cells).unwrap();
+    /// encoder.finish(&mut cells).unwrap();
+    /// assert_eq!(
+    ///     encoder.diagnostics(),
+    ///     [Diagnostic::Unopened { character: ')', offset: Offset::new(12, 6) }]
+    /// );
+    /// ```
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// 이 인코더의 `Unsupported` 설정으로 `keeps_word` 를 확인합니다.
+    #[cfg(feature = "std")]
+    fn keeps_word(&self, word: &str) -> bool {
+        keeps_word(word, self.options.unsupported)
+    }
+
+    /// `text` 를 점역하여 `result` 에 이어 적습니다.
+    ///
+    /// 나누어 넘긴 입력은 사이에 빈칸 없이 이어진 글로 보므로, 앞 입력의 마지막 단어에 이어지는
+    /// `text` 의 첫 단어에는 단어 첫머리에만 쓰는 약자(10.6)를 쓰지 않습니다.
+    pub fn encode(&mut self, text: &str, result: &mut Vec<u8>) -> Result<(), BrailleError> {
+        self.encode_words(text, result, &mut |_| Ok(()))
+    }
+
+    /// `encode` 와 같으며, 단어를 하나 점역할 때마다 그 결과를 `sink` 로 보냅니다.
+    pub fn encode_to_sink<S: BrailleSink + ?Sized>(
+        &mut self,
+        text: &str,
+        sink: &mut S,
+    ) -> Result<(), BrailleError> {
+        let mut cells = Vec::new();
+        let mut written = self.written;
+        let result = self.encode_words(text, &mut cells, &mut |cells| {
+            sink::push_cells(cells, &mut written, sink)
+        });
+        self.written = written;
+        result
+    }
+
+    /// `finish` 와 같으며 결과를 `sink` 로 보냅니다.
+    pub fn finish_to_sink<S: BrailleSink + ?Sized>(
+        &mut self,
+        sink: &mut S,
+    ) -> Result<(), BrailleError> {
+        let mut cells = Vec::new();
+        self.finish(&mut cells)?;
+        sink::push_cells(&mut cells, &mut self.written, sink)
+    }
+
+    /// 단어마다 점역하여 `result` 에 이어 적고, 단어가 끝날 때마다 `flush` 를 부릅니다.
+    fn encode_words(
+        &mut self,
+        text: &str,
+        result: &mut Vec<u8>,
+        flush: &mut dyn FnMut(&mut Vec<u8>) -> Result<(), BrailleError>,
+    ) -> Result<(), BrailleError> {
+        let consumed = self.consumed;
+        self.consumed = consumed + Offset::new(text.len(), text.chars().count());
+        self.text_offset = consumed;
+        if self.options.normalize
+            && let Cow::Owned(normalized) = normalize::normalize(text)
+        {
+            let start = self.diagnostics.len();
+            let encoded = self.encode_split_words(&normalized, result, flush);
+            // 오류와 짝 부호의 위치는 정규화하기 전의 원문 기준으로 돌려줍니다.
+            // 앞서 넘긴 입력의 위치는 이미 옮겼으므로 `consumed` 뒤의 위치만 옮깁니다.
+            let original = |offset: Offset| {
+                if offset.byte < consumed.byte {
+                    offset
+                } else {
+                    consumed + normalize::original_offset(text, offset - consumed)
+                }
+            };
+            for diagnostic in &mut self.diagnostics[start..] {
+                match diagnostic {
+                    Diagnostic::Unclosed { offset, .. } | Diagnostic::Unopened { offset, .. } => {
+                        *offset = original(*offset)
+                    }
+                }
+            }
+            self.pairing.map_offsets(original);
+            return encoded
+                .map_err(|e| e.map_offset(|offset| normalize::original_offset(text, offset)));
+        }
+        self.encode_split_words(text, result, flush)
+    }
+
+    fn encode_split_words(
+        &mut self,
+        text: &str,
+        result: &mut Vec<u8>,
+        flush: &mut dyn FnMut(&mut Vec<u8>) -> Result<(), BrailleError>,
+    ) -> Result<(), BrailleError> {
+        self.encode_word_range(text, 0..text.len(), result, flush)
+    }
+
+    /// `text` 에서 `range` 안에서 시작하는 단어만 점역합니다.
+    ///
+    /// 앞 단어와 뒤따르는 단어는 `range` 밖에 있어도 봅니다. 문서를 나누어 점역할 때 나눈 곳 앞의
+    /// 상태가 `is_clear` 이면 나누지 않고 점역한 결과와 같습니다.
+    pub(crate) fn encode_word_range(
+        &mut self,
+        text: &str,
+        range: Range<usize>,
+        result: &mut Vec<u8>,
+        flush: &mut dyn FnMut(&mut Vec<u8>) -> Result<(), BrailleError>,
+    ) -> Result<(), BrailleError> {
+        let unsupported = self.options.unsupported;
+        let byte_of = |word: &str| word.as_ptr() as usize - text.as_ptr() as usize;
+        let mut words = text
             .split(' ')
-            .filter(|word| !word.is_empty())
-            .collect::<Vec<&str>>();
+            .filter(|word| keeps_word(word, unsupported))
+            .peekable();
+        let mut prev_word: &str = "";
+        while let Some(word) = words.next_if(|word| byte_of(word) < range.start) {
+            prev_word = word;
+        }
+        // 지금 단어와 뒤따르는 두 단어만 들고 있으면 되므로 단어 목록을 따로 만들지 않습니다.
+        let mut window = [""; LOOKAHEAD + 1];
+        let mut len = 0;
+        while len < window.len()
+            && let Some(word) = words.next()
+        {
+            window[len] = word;
+            len += 1;
+        }
 
-        let mut word: &str = "";
-        let mut remaining_words = &words[..];
-        while !remaining_words.is_empty() {
-            let prev_word = word;
-            (word, remaining_words) = remaining_words.split_first().unwrap();
+        // 글자 위치는 범위를 기록할 때만 셉니다.
+        let mut counted = (0, 0);
+        while len > 0 && byte_of(window[0]) < range.end {
+            let word = window[0];
+            let remaining_words = &window[1..len];
+
+            self.word_offset = self.text_offset + Offset::of_slice(text, word);
+            if let Some(spans) = &mut self.spans {
+                let byte = byte_of(word);
+                counted = (byte, counted.1 + text[counted.0..byte].chars().count());
+                spans.word_base = spans.text_base + counted.1;
+            }
 
             let mut skip_count = 0;
+            let mut position = 0;
 
-            self.encode_word(word, prev_word, remaining_words, &mut skip_count, result)?;
+            self.encode_word(
+                word,
+                prev_word,
+                remaining_words,
+                &mut skip_count,
+                &mut position,
+                result,
+            )
+            .map_err(|e| {
+                // 단어 안의 위치를 원문 기준의 위치로 옮깁니다.
+                e.shift(Offset::of_slice(text, word) + Offset::of_char(word, position))
+            })?;
+            self.continues_word = false;
+            flush(result)?;
+
+            prev_word = word;
+            window.rotate_left(1);
+            len -= 1;
+            if let Some(word) = words.next() {
+                window[len] = word;
+                len += 1;
+            }
         }
+        self.continues_word = !text.is_empty();
         Ok(())
     }
 
+    /// `remaining_words` 는 뒤따르는 단어이며, 앞의 `LOOKAHEAD` 개만 봅니다.
     fn encode_word(
         &mut self,
         word: &str,
         prev_word: &str,
         remaining_words: &[&str],
         skip_count: &mut usize,
+        position: &mut usize,
         result: &mut Vec<u8>,
-    ) -> Result<(), String> {
-        if let Some((_, code, rest)) = word_shortcut::split_word_shortcut(word) {
+    ) -> Result<(), BrailleError> {
+        if let Some((key, code, rest)) = word_shortcut::split_word_shortcut(word)
+            && self.rules.builtin_enabled("rule_18")
+        {
             result.extend(code);
+            *position = key.chars().count();
+            self.mark_rule(0..*position, "rule_18", result);
             if !rest.is_empty() {
+                // 나머지 글자의 위치는 약어 뒤부터 셉니다.
+                let saved = self.spans.as_mut().map(|spans| {
+                    let saved = (spans.text_base, spans.word_base);
+                    spans.text_base = spans.word_base + *position;
+                    saved
+                });
+                let text_offset = self.text_offset;
+                self.text_offset = self.word_offset + Offset::of_char(word, *position);
                 // Recursively encode the rest using the current encoder state
-                self.encode(rest.as_str(), result)?;
+                self.continues_word = true;
+                self.encode_split_words(rest.as_str(), result, &mut |_| Ok(()))?;
+                self.text_offset = text_offset;
+                if let (Some(spans), Some((text_base, word_base))) = (&mut self.spans, saved) {
+                    spans.text_base = text_base;
+                    spans.word_base = word_base;
+                }
             }
         } else {
-            let word_chars = word.chars().collect::<Vec<char>>();
-            let word_len = word_chars.len();
-            let is_all_uppercase = word_chars.iter().all(|c| c.is_uppercase());
-            let has_korean_char = word_chars
-                .iter()
-                .any(|c| (0xAC00 <= *c as u32 && *c as u32 <= 0xD7A3));
+            let tokens = TokenizedWord::new(word);
+            let word_chars = &tokens.chars[..];
+            let word_len = tokens.len();
+            // 앞뒤 글자를 미리 살피는 규칙이 있으므로 점역할 수 없는 문자를 먼저 찾습니다.
+            if self.options.unsupported == Unsupported::Error
+                && let Some(i) = tokens.first_unsupported
+            {
+                *position = i;
+                CharType::new(word_chars[i])?;
+            }
+            let is_all_uppercase = tokens.is_all_uppercase;
+            let has_korean_char = tokens.has_korean_char;
 
-            if self.english_indicator && !self.is_english && word_chars[0].is_ascii_alphabetic() {
+            if self.english_indicator && !self.is_english && is_roman_letter(word_chars[0]) {
+                // 제29항 국어 문장 안에 로마자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다
                 // 제31항 국어 문장 안에 그리스 문자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다
-
                 self.is_english = true;
                 result.push(52);
+                let rule = if word_chars[0].is_ascii_alphabetic() {
+                    "rule_29"
+                } else {
+                    "rule_31"
+                };
+                self.mark_rule(0..0, rule, result);
             }
 
             if is_all_uppercase && !self.triple_big_english {
@@ -112,6 +456,7 @@ impl Encoder {
                     result.push(32);
                 }
             }
+            self.mark_rule(0..0, "rule_28", result);
 
             let mut is_number = false;
             let mut is_big_english = false;
@@ -123,16 +468,46 @@ impl Encoder {
                     continue;
                 }
 
-                let char_type = CharType::new(*c)?;
+                *position = i;
+                let char_type = match (tokens.types[i], self.options.unsupported) {
+                    (Some(char_type), _) => char_type,
+                    (None, Unsupported::Skip) => continue,
+                    (None, Unsupported::Replace(cell)) => {
+                        result.push(cell.bits());
+                        self.mark(i..i + 1, result);
+                        continue;
+                    }
+                    (None, Unsupported::Error) => return Err(CharType::new(*c).unwrap_err()),
+                };
+
+                if self.is_english
+                    && let Some(code) = english::encode_english_punctuation(*c)
+                    && continues_roman(&word_chars[i + 1..], remaining_words)
+                {
+                    // 로마자 사이의 문장 부호는 로마자 종료표 없이 ｢통일영어점자 규정｣의 부호로 적는다.
+                    result.extend(code);
+                    let rule = if *c == '.' && english::is_abbreviation_period(word_chars, i) {
+                        // 제30항 로마자 약어의 마침표
+                        "rule_30"
+                    } else {
+                        "rule_28"
+                    };
+                    self.mark_rule(i..i + 1, rule, result);
+                    self.mark(i..i + 1, result);
+                    is_number = false;
+                    is_big_english = false;
+                    continue;
+                }
 
-                if self.english_indicator && i > 0 && !c.is_ascii_alphabetic() {
-                    // 제31항 국어 문장 안에 그리스 문자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다
-                    if self.is_english && !['"', ')', '('].contains(c) {
+                if self.english_indicator && i > 0 && !is_roman_letter(*c) {
+                    // 제29항, 제31항 로마자나 그리스 문자 뒤에는 로마자 종료표 ⠲을 적는다
+                    if self.is_english && !pairing::is_paired(*c) {
                         // 제34항 로마자가 따옴표나 괄호 등으로 묶일 때에는 로마자 종료표를 적지 않는다.
                         result.push(50);
                     }
                     self.is_english = false;
                 }
+                self.mark_rule(i..i, "rule_29", result);
 
                 match char_type {
                     CharType::Korean(korean) => {
@@ -142,36 +517,50 @@ impl Encoder {
                         {
                             // 44항 [다만] 숫자와 혼동되는 ‘ㄴ, ㄷ, ㅁ, ㅋ, ㅌ, ㅍ, ㅎ’의 첫소리 글자와 ‘운’의 약자는 숫자 뒤에 붙어 나오더라도 숫자와 한글을 띄어 쓴다.
                             result.push(0);
+                            self.mark_rule(i..i, "rule_44_b1", result);
                         }
 
-                        // "겄"의 경우 4항으로 해석해야 하지만 "것 + ㅅ" 으로 해석될 여지가 있으므로 예외처리
-                        if ['팠', '껐', '셩', '쎵', '졍', '쪙', '쳥', '겄'].contains(c) {
-                            // 14항 [붙임] "팠"을 적을 때에는 "ㅏ"를 생략하지 않고 적는다.
-                            // 16항 [붙임] ‘껐’을 적을 때에는 ‘꺼’와 받침 ‘ㅆ’ 약자를 어울러 적는다.
-                            // 제17항 ‘성, 썽, 정, 쩡, 청’을 적을 때에는 ‘ㅅ, ㅆ, ㅈ, ㅉ, ㅊ’ 다음에 ‘영’ 의 약자 ⠻을 적어 나타낸다. -> 그러므로 셩, 쪙 등 [ㅅ, ㅆ, ㅈ, ㅉ, ㅊ] + 영의 경우 초, 중, 종성 모두 결합
-                            let (cho0, cho1) = split_korean_jauem(korean.cho)?;
-                            if cho1.is_some() {
-                                // 쌍자음 경우의 수
-                                result.push(32);
+                        let context = SyllableContext {
+                            word: word_chars,
+                            index: i,
+                            korean: &korean,
+                        };
+                        let mut marks = Vec::new();
+                        if !self.rules.encode(
+                            RulePhase::Syllable,
+                            &context,
+                            result,
+                            |rule, result| marks.push((rule, result.len())),
+                        )? {
+                            if self.spans.is_none()
+                                && self.rules.syllable_contractions()
+                                && let Some(cells) = syllable::syllable_cells(*c)
+                            {
+                                // 규정을 기록하지 않을 때에는 미리 계산한 표를 씁니다.
+                                result.extend_from_slice(cells);
+                            } else {
+                                let start = result.len();
+                                let (code, rules) = encode_korean_char(&korean, |id| {
+                                    self.rules.builtin_enabled(id)
+                                })?;
+                                result.extend(code);
+                                marks.extend(
+                                    rules.into_iter().map(|(rule, end)| (rule, start + end)),
+                                );
                             }
-                            result.push(encode_choseong(cho0)?);
-                            result.extend(encode_jungsong(korean.jung)?);
-                            result.extend(encode_jongseong(korean.jong.unwrap())?);
-                        } else if ['나', '다', '마', '바', '자', '카', '타', '파', '하'].contains(c)
-                            && i < word_len - 1
-                            && has_choseong_o(word_chars[i + 1])
-                        {
-                            // 14항 ‘나, 다, 마, 바, 자, 카, 타, 파, 하’에 모음이 붙어 나올 때에는 약자를 사용하지 않는다
-                            result.push(encode_choseong(korean.cho)?);
-                            result.extend(encode_jungsong(korean.jung)?);
-                        } else {
-                            result.extend(encode_korean_char(&korean)?);
+                        }
+                        for (rule, end) in marks {
+                            self.mark_rule(i..i + 1, rule, &result[..end]);
                         }
 
-                        if i < word_len - 1 {
-                            // 11 - 모음자에 ‘예’가 붙어 나올 때에는 그 사이에 구분표 -을 적어 나타낸다
-                            rule_11(&korean, word_chars[i + 1], result)?;
-                            rule_12(&korean, word_chars[i + 1], result)?;
+                        // 11, 12항 구분표
+                        let mut marks = Vec::new();
+                        self.rules
+                            .encode(RulePhase::After, &context, result, |rule, result| {
+                                marks.push((rule, result.len()))
+                            })?;
+                        for (rule, end) in marks {
+                            self.mark_rule(i + 1..i + 1, rule, &result[..end]);
                         }
                     }
                     CharType::KoreanPart(c) => {
@@ -180,46 +569,49 @@ impl Encoder {
                                 // 8항 - 단독으로 쓰인 자모
                                 result.push(63);
                                 result.extend(korean_part::encode_korean_part(c)?);
+                                self.mark_rule(i..i + 1, "rule_8", result);
                             }
                             2 => {
                                 // 9항 - 한글의 자음자가 번호로 쓰이는 경우
                                 if i == 0 && word_chars[1] == '.' {
                                     result.push(63);
                                     result.extend(jauem::jongseong::encode_jongseong(c)?);
+                                    self.mark_rule(i..i + 1, "rule_9", result);
                                 } else {
                                     // 8항 - 단독으로 쓰인 자모
                                     result.push(63);
                                     result.extend(korean_part::encode_korean_part(c)?);
+                                    self.mark_rule(i..i + 1, "rule_8", result);
                                 }
                             }
                             _ => {
                                 if (i == 0 && word_len > 1 && word_chars[1] == '자')
                                     || ((i == 0
                                         || (i > 0
-                                            && matches!(
-                                                CharType::new(word_chars[i - 1])?,
-                                                CharType::Symbol(_)
-                                            )))
+                                            && tokens.is_type(i - 1, |c| {
+                                                matches!(c, CharType::Symbol(_))
+                                            })))
                                         && (word_len - 1 == i
-                                            || (i < word_len - 1
-                                                && matches!(
-                                                    CharType::new(word_chars[i + 1])?,
-                                                    CharType::Symbol(_)
-                                                ))))
+                                            || tokens.is_type(i + 1, |c| {
+                                                matches!(c, CharType::Symbol(_))
+                                            })))
                                 {
                                     // 8항 - 단독으로 쓰인 자모
                                     result.push(63);
                                     result.extend(korean_part::encode_korean_part(c)?);
+                                    self.mark_rule(i..i + 1, "rule_8", result);
                                 } else {
                                     if has_korean_char {
                                         // 10항 - 단독으로 쓰인 자음자가 단어에 붙어 나올 때
                                         result.push(56);
                                         result.extend(korean_part::encode_korean_part(c)?);
+                                        self.mark_rule(i..i + 1, "rule_10", result);
                                     } else {
                                         // 10항 - 단독으로 쓰인 자음자가 단어에 붙어 나올 때
                                         // 8항 - 단독으로 쓰인 자모
                                         result.push(63);
                                         result.extend(korean_part::encode_korean_part(c)?);
+                                        self.mark_rule(i..i + 1, "rule_8", result);
                                     }
                                 }
                             }
@@ -230,6 +622,7 @@ impl Encoder {
                             // 제31항 국어 문장 안에 그리스 문자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다
 
                             result.push(52);
+                            self.mark_rule(i..i, "rule_29", result);
                         }
 
                         if (!is_all_uppercase || word_len < 2)
@@ -241,7 +634,7 @@ impl Encoder {
                             // 앞에 대문자 구절표 ⠠⠠⠠을 적고, 마지막 단어 뒤에 대문자 종료표 ⠠⠄을 적는다.
                             is_big_english = true;
 
-                            for idx in 0..std::cmp::min(word_len - i, 2) {
+                            for idx in 0..core::cmp::min(word_len - i, 2) {
                                 if word_chars[i + idx].is_uppercase() {
                                     result.push(32);
                                 } else {
@@ -249,30 +642,52 @@ impl Encoder {
                                 }
                             }
                         }
-                        if !self.is_english || i == 0 {
-                            if let Some((code, len)) = rule_en_10_6(
-                                &word_chars[i..].iter().collect::<String>().to_lowercase(),
-                            ) {
+                        self.mark_rule(i..i, "rule_28", result);
+                        if !self.options.english_contractions {
+                            result.push(english::encode_english(c)?);
+                        } else if !self.is_english || (i == 0 && !self.continues_word) {
+                            if let Some((code, len)) = rule_en_10_6(tokens.lowercase_from(i)) {
                                 result.push(code);
                                 *skip_count = len;
-                            } else if let Some((code, len)) = rule_en_10_4(
-                                &word_chars[i..].iter().collect::<String>().to_lowercase(),
-                            ) {
+                            } else if let Some((code, len)) = rule_en_10_4(tokens.lowercase_from(i))
+                            {
                                 result.push(code);
                                 *skip_count = len;
                             } else {
                                 result.push(english::encode_english(c)?);
                             }
-                        } else if let Some((code, len)) =
-                            rule_en_10_4(&word_chars[i..].iter().collect::<String>().to_lowercase())
-                        {
+                        } else if let Some((code, len)) = rule_en_10_4(tokens.lowercase_from(i)) {
                             result.push(code);
                             *skip_count = len;
                         } else {
                             result.push(english::encode_english(c)?);
                         }
+                        self.mark_rule(i..i + 1 + *skip_count, "rule_28", result);
                         self.is_english = true;
                     }
+                    CharType::Greek(c) => {
+                        if self.english_indicator && !self.is_english {
+                            // 제31항 국어 문장 안에 그리스 문자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다
+                            result.push(52);
+                        }
+                        if greek::is_greek_uppercase(c) {
+                            result.push(32);
+                        }
+                        result.push(greek::GREEK_INDICATOR);
+                        result.push(greek::encode_greek(c)?);
+                        self.mark_rule(i..i + 1, "rule_31", result);
+                        self.is_english = true;
+                    }
+                    CharType::OldHangul(c) => {
+                        if is_number && old_hangul::is_number_like_choseong(c) {
+                            // 44항 [다만] 옛한글도 ‘ㄴ, ㄷ, ㅁ, ㅋ, ㅌ, ㅍ, ㅎ’의 첫소리 글자는 숫자와 띄어 쓴다.
+                            result.push(0);
+                            self.mark_rule(i..i, "rule_44_b1", result);
+                        }
+                        // 옛한글 음절은 약자 없이 자모를 하나씩 적습니다.
+                        result.extend(old_hangul::encode_old_hangul(c)?);
+                        self.mark_rule(i..i + 1, "old_hangul", result);
+                    }
                     CharType::Number(c) => {
                         if !is_number {
                             // 제43항 숫자 사이에 마침표, 쉼표, 연결표가 붙어 나올 때에는 뒤의 숫자에 수표를 적지 않는다.
@@ -282,7 +697,9 @@ impl Encoder {
                             }
                             is_number = true;
                         }
+                        self.mark_rule(i..i, "rule_40", result);
                         result.extend(number::encode_number(c));
+                        self.mark_rule(i..i + 1, "rule_40", result);
                     }
                     CharType::Symbol(c) => {
                         if c == ','
@@ -292,42 +709,58 @@ impl Encoder {
                         {
                             // 제41항 숫자 사이에 붙어 나오는 쉼표와 자릿점은 ⠂으로 적는다.
                             result.push(2);
+                            self.mark_rule(i..i + 1, "rule_41", result);
+                        } else if pairing::is_paired(c)
+                            && !pairing::is_apostrophe(
+                                i.checked_sub(1).map(|prev| word_chars[prev]),
+                                c,
+                                word_chars.get(i + 1).copied(),
+                            )
+                        {
+                            // 짝이 맞지 않는 부호는 점역을 멈추지 않고 `diagnostics` 에 모읍니다.
+                            let offset = self.word_offset + Offset::of_char(word, i);
+                            let diagnostics = &mut self.diagnostics;
+                            let open = self.pairing.push(c, offset, |d| diagnostics.push(d));
+                            match pairing::encode_quote(c, open) {
+                                Some(code) => result.extend(code),
+                                None => {
+                                    result.extend(symbol_shortcut::encode_char_symbol_shortcut(c)?)
+                                }
+                            }
+                            self.mark_rule(i..i + 1, "paired_punctuation", result);
                         } else {
                             result.extend(symbol_shortcut::encode_char_symbol_shortcut(c)?);
                         }
                     }
                     CharType::Space(c) => {
-                        result.push(if c == '\n' { 255 } else { 0 });
+                        result.push(if c == '\n' && self.options.newline == Newline::Keep {
+                            255
+                        } else {
+                            0
+                        });
                     }
                     CharType::MathSymbol(c) => {
-                        if i > 0
-                            && word_chars[..i]
-                                .iter()
-                                .any(|c| (0xAC00 <= *c as u32 && *c as u32 <= 0xD7A3))
-                        {
+                        if tokens.has_korean_before(i) {
                             result.push(0);
                         }
+                        self.mark_rule(i..i, "rule_46", result);
                         result.extend(math_symbol_shortcut::encode_char_math_symbol_shortcut(c)?);
-                        if i < word_len - 1 {
-                            let mut korean = vec![];
-                            for wc in word_chars[i..].iter() {
-                                if 0xAC00 <= *wc as u32 && *wc as u32 <= 0xD7A3 {
-                                    korean.push(*wc);
-                                } else if !korean.is_empty() {
-                                    break;
-                                }
-                            }
-                            if !korean.is_empty() {
-                                // 조사일 경우, 수 뒤에 올 경우 구분하는 것으로 판단
-                                if !["과", "와", "이다", "하고", "이랑", "와", "랑", "아니다"]
-                                    .contains(&korean.iter().collect::<String>().as_str())
-                                {
-                                    result.push(0);
-                                }
+                        self.mark_rule(i..i + 1, "rule_45", result);
+                        if i < word_len - 1
+                            && let Some(korean) = tokens.korean_after(i)
+                        {
+                            // 조사일 경우, 수 뒤에 올 경우 구분하는 것으로 판단
+                            if !["과", "와", "이다", "하고", "이랑", "랑", "아니다"]
+                                .iter()
+                                .any(|particle| particle.chars().eq(korean.iter().copied()))
+                            {
+                                result.push(0);
+                                self.mark_rule(i + 1..i + 1, "rule_46", result);
                             }
                         }
                     }
                 }
+                self.mark(i..i + 1 + *skip_count, result);
                 if !c.is_numeric() {
                     is_number = false;
                 }
@@ -337,35 +770,43 @@ impl Encoder {
             }
         }
 
-        if self.triple_big_english {
-            if !(remaining_words
+        if self.triple_big_english
+            && !(remaining_words
                 .first()
                 .is_some_and(|w| w.chars().all(|c| c.is_ascii_alphabetic())))
-            {
-                // 28항 [붙임] 로마자가 한 글자만 대문자일 때에는 대문자 기호표 ⠠을 그 앞에 적고, 단어 전체가 대문자이거나 두 글자 이상 연속해서 대문자일 때에는 대문자 단어표
-                // ⠠⠠을 그 앞에 적는다. 세 개 이상의 연속된 단어가 모두 대문자일 때에는 첫 단어
-                // 앞에 대문자 구절표 ⠠⠠⠠을 적고, 마지막 단어 뒤에 대문자 종료표 ⠠⠄을 적는다.
-                result.push(32);
-                result.push(4);
-                self.triple_big_english = false; // Reset after adding terminator
-            }
+        {
+            // 28항 [붙임] 로마자가 한 글자만 대문자일 때에는 대문자 기호표 ⠠을 그 앞에 적고, 단어 전체가 대문자이거나 두 글자 이상 연속해서 대문자일 때에는 대문자 단어표
+            // ⠠⠠을 그 앞에 적는다. 세 개 이상의 연속된 단어가 모두 대문자일 때에는 첫 단어
+            // 앞에 대문자 구절표 ⠠⠠⠠을 적고, 마지막 단어 뒤에 대문자 종료표 ⠠⠄을 적는다.
+            result.push(32);
+            result.push(4);
+            self.triple_big_english = false; // Reset after adding terminator
         }
+        let word_end = if self.spans.is_some() {
+            word.chars().count()
+        } else {
+            0
+        };
+        self.mark_rule(word_end..word_end, "rule_28", result);
         if !remaining_words.is_empty() {
             if self.english_indicator
-                && !remaining_words[0]
-                    .chars()
-                    .next()
-                    .unwrap()
-                    .is_ascii_alphabetic()
+                && !is_roman_letter(remaining_words[0].chars().next().unwrap())
             {
-                // 제31항 국어 문장 안에 그리스 문자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다
+                // 제29항, 제31항 로마자나 그리스 문자 뒤에는 로마자 종료표 ⠲을 적는다
                 if self.is_english {
                     result.push(50);
                 }
                 self.is_english = false;
             }
+            self.mark_rule(word_end..word_end, "rule_29", result);
 
             result.push(0);
+            if self.spans.is_some() {
+                // 단어 사이의 띄어쓰기는 모두 ' ' 이므로 바이트 수가 곧 글자 수입니다.
+                let gap =
+                    remaining_words[0].as_ptr() as usize - word.as_ptr() as usize - word.len();
+                self.mark(word_end..word_end + gap, result);
+            }
         }
 
         // Update state for next iteration
@@ -375,46 +816,204 @@ impl Encoder {
         Ok(())
     }
 
-    pub fn finish(&mut self, result: &mut Vec<u8>) -> Result<(), String> {
+    pub fn finish(&mut self, result: &mut Vec<u8>) -> Result<(), BrailleError> {
         // Handle any end-of-stream processing
         if self.triple_big_english {
             // Close triple big english if still active
             result.push(32); // ⠠
             result.push(4); // ⠄
         }
+        let diagnostics = &mut self.diagnostics;
+        self.pairing.finish(|d| diagnostics.push(d));
+        if let Some(spans) = &mut self.spans {
+            let end = spans.end();
+            spans.word_base = 0;
+            spans.record(end..end, Some("rule_28"), result.len());
+        }
         Ok(())
     }
 }
 
-pub fn encode(text: &str) -> Result<Vec<u8>, String> {
-    // 한국어가 존재할 경우 english_indicator 가 true 가 됩니다.
-    let english_indicator = text.split(' ').filter(|word| !word.is_empty()).any(|word| {
-        word.chars().any(|c| {
-            (c as u32 >= 0x3131 && c as u32 <= 0x3163) || (0xAC00 <= c as u32 && c as u32 <= 0xD7A3)
-        })
-    });
+/// 문장 전체를 보고 `Context::Auto` 를 정한 `Encoder` 를 만듭니다.
+fn encoder_for(text: &str, options: &EncodeOptions) -> Encoder {
+    let mut encoder = Encoder::with_options(options.clone());
+    encoder.english_indicator = options.english_indicator(text);
+    encoder
+}
 
-    let mut encoder = Encoder::new(english_indicator);
+pub fn encode(text: &str) -> Result<Vec<u8>, BrailleError> {
+    encode_with_options(text, &EncodeOptions::default())
+}
+
+pub fn encode_with_options(text: &str, options: &EncodeOptions) -> Result<Vec<u8>, BrailleError> {
+    let mut encoder = encoder_for(text, options);
     let mut result = Vec::new();
     encoder.encode(text, &mut result)?;
     encoder.finish(&mut result)?;
     Ok(result)
 }
 
-pub fn encode_to_unicode(text: &str) -> Result<String, String> {
-    let result = encode(text)?;
-    Ok(result
-        .iter()
-        .map(|c| unicode::encode_unicode(*c))
-        .collect::<String>())
+/// 점역 결과를 빈칸, 줄바꿈이 구분된 `BrailleToken` 으로 돌려줍니다.
+pub fn encode_to_tokens(text: &str) -> Result<Vec<BrailleToken>, BrailleError> {
+    encode_to_tokens_with_options(text, &EncodeOptions::default())
+}
+
+pub fn encode_to_tokens_with_options(
+    text: &str,
+    options: &EncodeOptions,
+) -> Result<Vec<BrailleToken>, BrailleError> {
+    let mut tokens = Vec::new();
+    encode_to_sink(text, options, &mut tokens)?;
+    Ok(tokens)
+}
+
+/// 점역 결과를 중간 버퍼 없이 `sink` 로 바로 보냅니다.
+pub fn encode_to_sink<S: BrailleSink + ?Sized>(
+    text: &str,
+    options: &EncodeOptions,
+    sink: &mut S,
+) -> Result<(), BrailleError> {
+    let mut encoder = encoder_for(text, options);
+    encoder.encode_to_sink(text, sink)?;
+    encoder.finish_to_sink(sink)
+}
+
+/// 점역 결과를 `buffer` 에 적고 적은 칸 수를 돌려줍니다.
+///
+/// 결과를 담을 `Vec` 을 만들지 않으므로 할당을 줄여야 하는 점자 정보 단말기나 WASM 에서 씁니다.
+/// 버퍼는 `required_len` 만큼 잡으면 충분합니다.
+///
+/// 할당이 전혀 없지는 않습니다. 단어를 글자로 나누고 한 단어의 셀을 모아 두는 임시 버퍼를 쓰며,
+/// 정규화할 글자가 있으면 입력 전체를 정규화한 사본을 만듭니다. 임시 버퍼는 입력 전체가 아닌
+/// 가장 긴 단어의 크기에 비례합니다.
+///
+/// ```
+/// let mut buffer = [0; 64];
+/// assert!(braillify::required_len("안녕하세요") <= buffer.len());
+/// let len = braillify::encode_into("안녕하세요", &mut buffer).unwrap();
+/// assert_eq!(&buffer[..len], braillify::encode("안녕하세요").unwrap());
+/// ```
+pub fn encode_into(text: &str, buffer: &mut [u8]) -> Result<usize, BrailleError> {
+    encode_into_with_options(text, &EncodeOptions::default(), buffer)
+}
+
+/// 버퍼가 작으면 `BrailleError::BufferTooSmall` 이며 `required` 는 실제로 필요한 칸 수입니다.
+/// 이때 버퍼에는 앞부분만 적혀 있습니다.
+pub fn encode_into_with_options(
+    text: &str,
+    options: &EncodeOptions,
+    buffer: &mut [u8],
+) -> Result<usize, BrailleError> {
+    let capacity = buffer.len();
+    let mut sink = SliceSink::new(buffer);
+    encode_to_sink(text, options, &mut sink)?;
+    match sink.len() {
+        required if required > capacity => Err(BrailleError::BufferTooSmall { required, capacity }),
+        len => Ok(len),
+    }
+}
+
+/// `encode_into` 에 넘길 버퍼 크기, 점역하지 않고 글자 종류만 보고 어림합니다.
+///
+/// 점역 결과의 칸 수보다 작지 않지만 몇 배까지 클 수 있습니다.
+/// `EncodeOptions::rule` 로 더한 규칙이 적는 칸은 셈에 넣지 않습니다.
+pub fn required_len(text: &str) -> usize {
+    // 단어마다 빈칸, 로마자표와 종료표, 대문자 단어표(2칸)가 붙을 수 있습니다.
+    const WORD: usize = 5;
+    let words = text.split(' ').filter(|word| !word.is_empty()).count();
+    let chars: usize = normalize::normalized(text)
+        .map(|c| match CharType::new(c) {
+            // 된소리표, 초성, 중성 2칸, 겹받침 2칸에 제11항 등으로 덧붙는 칸
+            Ok(CharType::Korean(_)) => 8,
+            // 온표와 앞뒤를 띄우는 빈칸
+            Ok(CharType::KoreanPart(c)) => {
+                korean_part::encode_korean_part(c).map_or(2, <[u8]>::len) + 2
+            }
+            // 대문자표나 수표, 바뀌는 글자 종류 사이의 연결표
+            Ok(CharType::OldHangul(c)) => old_hangul::encode_old_hangul(c).map_or(2, <[u8]>::len),
+            Ok(CharType::English(_) | CharType::Number(_)) => 3,
+            // 로마자표, 대문자 기호표, 그리스 문자표와 로마자 종료표
+            Ok(CharType::Greek(_)) => 5,
+            Ok(CharType::Symbol(c)) => {
+                symbol_shortcut::encode_char_symbol_shortcut(c).map_or(2, <[u8]>::len) + 2
+            }
+            Ok(CharType::MathSymbol(c)) => {
+                math_symbol_shortcut::encode_char_math_symbol_shortcut(c).map_or(2, <[u8]>::len) + 2
+            }
+            // 빈칸, 줄바꿈, `Unsupported::Replace` 의 대체 칸
+            Ok(CharType::Space(_)) | Err(_) => 1,
+        })
+        .sum();
+    chars + words * WORD
+}
+
+/// 점역 결과와 함께 각 글자가 만든 셀 범위를 돌려줍니다.
+///
+/// 점자 단말기의 커서 라우팅 키로 묵자 커서를 옮기거나 그 반대로 옮길 때 사용합니다.
+pub fn encode_with_spans(text: &str) -> Result<SpannedEncoding, BrailleError> {
+    let (cells, spans) = encode_recorded(text)?;
+    Ok(SpannedEncoding {
+        cells,
+        spans: spans.into_spans(),
+    })
 }
 
-pub fn encode_to_braille_font(text: &str) -> Result<String, String> {
-    let result = encode(text)?;
-    Ok(result
-        .iter()
-        .map(|c| unicode::encode_unicode(*c))
-        .collect::<String>())
+/// 점역 결과와 함께 셀 묶음마다 그 셀을 만든 규정(`rule_map.json` 의 ID, 제목)을 돌려줍니다.
+///
+/// 한 글자가 여러 규정으로 점역되면 (예: ‘껐’의 된소리표와 받침) 규정마다 묶음이 나뉩니다.
+pub fn encode_explained(text: &str) -> Result<ExplainedEncoding, BrailleError> {
+    let (cells, spans) = encode_recorded(text)?;
+    Ok(ExplainedEncoding {
+        cells,
+        spans: spans.into_explained(),
+    })
+}
+
+/// 셀마다 글자 범위와 규정을 기록하며 점역합니다. 글자 범위는 정규화하기 전의 원문 기준입니다.
+fn encode_recorded(text: &str) -> Result<(Vec<u8>, SpanRecorder), BrailleError> {
+    let mut encoder = encoder_for(text, &EncodeOptions::default());
+    encoder.spans = Some(SpanRecorder::default());
+    let mut cells = Vec::new();
+    encoder.encode(text, &mut cells)?;
+    encoder.finish(&mut cells)?;
+    let mut spans = encoder.spans.take().unwrap_or_default();
+    if let Cow::Owned(_) = normalize::normalize(text) {
+        let origins = normalize::origins(text);
+        spans.map_sources(|char| origins[char]);
+    }
+    Ok((cells, spans))
+}
+
+pub fn encode_to_unicode(text: &str) -> Result<String, BrailleError> {
+    encode_to_unicode_with_options(text, &EncodeOptions::default())
+}
+
+pub fn encode_to_unicode_with_options(
+    text: &str,
+    options: &EncodeOptions,
+) -> Result<String, BrailleError> {
+    let mut sink = UnicodeSink::new();
+    encode_to_sink(text, options, &mut sink)?;
+    Ok(sink.into_string())
+}
+
+/// 북미 ASCII 점자(점자 폰트, 점자 프린터용 문자)로 점역합니다. 줄바꿈은 `'\n'` 입니다.
+pub fn encode_to_braille_font(text: &str) -> Result<String, BrailleError> {
+    let mut sink = AsciiSink::new();
+    encode_to_sink(text, &EncodeOptions::default(), &mut sink)?;
+    Ok(sink.into_string())
+}
+
+/// 점자 프린터(엠보서)에 보낼 `.brf` 파일 내용으로 점역합니다.
+///
+/// `encode_to_braille_font` 와 같은 ASCII 점자이며, 줄은 CRLF 로 끝납니다.
+pub fn encode_to_brf(text: &str) -> Result<String, BrailleError> {
+    Ok(ascii::encode_brf(&encode(text)?))
+}
+
+/// 점역 결과를 PEF(Portable Embosser Format) 1.0 문서로 만듭니다.
+pub fn encode_to_pef(text: &str, options: &PefOptions) -> Result<String, BrailleError> {
+    Ok(to_pef(&encode_with_spans(text)?, options))
 }
 
 #[cfg(test)]
@@ -425,6 +1024,36 @@ mod test {
     use proptest::prelude::*;
 
     use super::*;
+
+    /// `test_cases` 의 모든 CSV 에서 입력과 기대하는 셀을 읽습니다.
+    pub(crate) fn test_cases() -> Vec<(String, Vec<u8>)> {
+        let test_cases_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../../test_cases");
+        let mut cases = Vec::new();
+        for entry in std::fs::read_dir(test_cases_dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().unwrap_or_default() != "csv" {
+                continue;
+            }
+            let reader = csv::ReaderBuilder::new()
+                .has_headers(false)
+                .from_path(&path)
+                .unwrap();
+            for record in reader.into_records() {
+                let record = record.unwrap();
+                let expected = record[3]
+                    .chars()
+                    .map(|c| match c {
+                        ' ' => 0,
+                        '\n' => 255,
+                        _ => unicode::try_decode_unicode(c).unwrap(),
+                    })
+                    .collect();
+                cases.push((record[0].to_string(), expected));
+            }
+        }
+        cases
+    }
+
     #[test]
     pub fn test_encode() {
         assert_eq!(encode_to_unicode("상상이상의 ").unwrap(), "⠇⠶⠇⠶⠕⠇⠶⠺");
@@ -618,7 +1247,7 @@ mod test {
                             input.to_string(),
                             record[3].to_string(),
                             braille_expected.clone(),
-                            record[3].to_string() == braille_expected,
+                            record[3] == braille_expected,
                         ));
                     }
                     Err(e) => {
@@ -751,7 +1380,7 @@ mod test {
         #[test]
         fn test_encode_proptest(s: String) {
             let result = encode(&s);
-            let _encoded = match result {
+            let encoded = match result {
                 Ok(encoded) => {
                     // Empty result is valid for strings that contain only spaces
                     let is_only_spaces = s.chars().all(|c| c == ' ');
@@ -770,11 +1399,612 @@ mod test {
                 }
             };
 
-            // let decoded = decode(&encoded);
-            // assert_eq!(s, decoded, "Decoded string does not match original input: {}", s);
+            // 역점역은 항상 성공해야 한다
+            assert!(decode(&encoded).is_ok());
+        }
+
+        #[test]
+        fn test_decode_roundtrip_proptest(
+            words in prop::collection::vec(
+                (
+                    prop::sample::select(&[
+                        "안녕하세요", "그래서", "그러나", "사람", "점자", "한국어", "세상", "아름다운",
+                        "것이", "모든", "청소를", "껐어요", "시예요", "소화액", "끊겼어요", "작동하나요",
+                        "나라", "학교", "우리", "바다", "하늘", "까치", "싸움", "떠났다", "옛날",
+                        "이야기", "않았다", "읽었다", "값", "정성", "계절", "위에", "의사",
+                    ][..]),
+                    // 따옴표로 묶은 단어, 작은따옴표는 ‘ ’ 로 역점역됩니다.
+                    prop::sample::select(&[("", ""), ("\"", "\""), ("‘", "’")][..]),
+                ),
+                1..8,
+            )
+        ) {
+            let s = words
+                .iter()
+                .map(|(word, (open, close))| format!("{open}{word}{close}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let decoded = decode(&encode(&s).unwrap()).unwrap();
+            assert_eq!(s, decoded, "Decoded string does not match original input: {}", s);
+        }
+    }
+
+    #[test]
+    fn test_encode_uncontracted() {
+        let uncontracted = |text: &str| {
+            encode_to_unicode_with_options(text, &EncodeOptions::uncontracted()).unwrap()
+        };
+        assert_eq!(uncontracted("가나다"), "⠈⠣⠉⠣⠊⠣");
+        assert_eq!(uncontracted("억언얼"), "⠎⠁⠎⠒⠎⠂");
+        assert_eq!(uncontracted("성정청"), "⠠⠎⠶⠨⠎⠶⠰⠎⠶");
+        assert_eq!(uncontracted("까싸껏"), "⠠⠈⠣⠠⠠⠣⠠⠈⠎⠄");
+        assert_eq!(uncontracted("그래서 그리고"), "⠈⠪⠐⠗⠠⠎⠀⠈⠪⠐⠕⠈⠥");
+        assert_eq!(uncontracted("것을"), "⠈⠎⠄⠪⠂");
+        assert_eq!(uncontracted("했다"), "⠚⠗⠌⠊⠣");
+
+        // 약자, 약어 규정으로 점역된 셀이 없어야 한다
+        let mut encoder = Encoder::with_options(EncodeOptions::uncontracted());
+        encoder.spans = Some(SpanRecorder::default());
+        let mut cells = Vec::new();
+        encoder
+            .encode("그래서 나는 억울한 성정을 가졌다", &mut cells)
+            .unwrap();
+        let spans = encoder.spans.take().unwrap().into_explained();
+        assert!(spans.iter().filter_map(|span| span.rule).all(|rule| {
+            !["rule_13", "rule_15", "rule_16", "rule_17", "rule_18"].contains(&rule.id)
+        }));
+    }
+
+    proptest! {
+        #[test]
+        fn test_uncontracted_roundtrip_proptest(s in "[가-힣]{1,6}( [가-힣]{1,6}){0,3}") {
+            let encoded = encode_with_options(&s, &EncodeOptions::uncontracted()).unwrap();
+            // 단어 끝의 ⠖, ⠦, ⠲ (받침 ‘ㅋ, ㅌ, ㅍ’)은 역점역에서 문장 부호로 읽힌다
+            prop_assume!(
+                encoded
+                    .split(|cell| *cell == 0)
+                    .all(|word| !matches!(word.last(), Some(22 | 38 | 50)))
+            );
+            assert_eq!(decode_uncontracted(&encoded).unwrap(), s);
         }
     }
 
+    fn assert_spans(text: &str) {
+        let encoding = encode_with_spans(text).unwrap();
+        assert_eq!(encoding.cells, encode(text).unwrap(), "{text}");
+        let char_len = text.chars().count();
+        let mut cell = 0;
+        let mut source = 0;
+        for span in &encoding.spans {
+            // 셀 범위는 빈틈없이 이어지고 글자 범위는 앞으로만 나아간다
+            assert_eq!(span.cells.start, cell, "{text}: {:?}", encoding.spans);
+            assert!(span.cells.start < span.cells.end, "{text}");
+            assert!(span.source.start >= source, "{text}: {:?}", encoding.spans);
+            assert!(span.source.end <= char_len, "{text}: {:?}", encoding.spans);
+            cell = span.cells.end;
+            source = span.source.start;
+        }
+        assert_eq!(cell, encoding.cells.len(), "{text}");
+    }
+
+    #[test]
+    fn test_encode_with_spans() {
+        let encoding = encode_with_spans("나 ABC는").unwrap();
+        let spans = encoding
+            .spans
+            .iter()
+            .map(|span| (span.source.clone(), span.cells.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            vec![
+                (0..1, 0..1),
+                (1..2, 1..2),
+                // 로마자표와 대문자 단어표
+                (2..2, 2..5),
+                (2..3, 5..6),
+                (3..4, 6..7),
+                (4..5, 7..8),
+                // 로마자 종료표
+                (5..5, 8..9),
+                (5..6, 9..11),
+            ]
+        );
+        assert_eq!(encoding.cells_of(3), Some(6..7));
+        assert_eq!(encoding.source_of(3), Some(2..2));
+
+        // 약어는 여러 글자가 한 범위가 되고, 뒤에 이어지는 글자는 원문 위치를 가진다
+        let encoding = encode_with_spans("그래서 그러나요").unwrap();
+        assert_eq!(encoding.spans[0].source, 0..3);
+        assert_eq!(encoding.cells_of(5), encoding.cells_of(4));
+        assert_eq!(encoding.source_of(encoding.cells.len() - 1), Some(7..8));
+
+        for text in [
+            "  가  나 ",
+            "1,000원",
+            "ABC DEF GHI 가",
+            "아예 야애",
+            "ㄱ. ㄴ자",
+            "3+4=7입니다",
+            "the child",
+            "안녕\n하세요",
+        ] {
+            assert_spans(text);
+        }
+
+        for (input, _) in test_cases() {
+            assert_spans(&input);
+        }
+    }
+
+    #[test]
+    fn test_encode_explained() {
+        let explain = |text: &str| {
+            let encoding = encode_explained(text).unwrap();
+            assert_eq!(encoding.cells, encode(text).unwrap());
+            encoding
+                .spans
+                .iter()
+                .map(|span| {
+                    (
+                        span.source.clone(),
+                        encoding.cells[span.cells.clone()].to_vec(),
+                        span.rule.map(|rule| rule.id),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            explain("팠"),
+            vec![(0..1, vec![25, 35, 12], Some("rule_14"))]
+        );
+        assert_eq!(
+            explain("껐다"),
+            vec![
+                (0..1, vec![32, 8, 14, 12], Some("rule_16")),
+                (1..2, vec![10], Some("rule_13")),
+            ]
+        );
+        assert_eq!(
+            explain("까닭"),
+            vec![
+                (0..1, vec![32], Some("rule_16")),
+                (0..1, vec![43], Some("rule_13")),
+                (1..2, vec![10], Some("rule_13")),
+                (1..2, vec![2, 1], Some("rule_5")),
+            ]
+        );
+        assert_eq!(
+            explain("5운6기"),
+            vec![
+                (0..0, vec![60], Some("rule_40")),
+                (0..1, vec![17], Some("rule_40")),
+                (1..1, vec![0], Some("rule_44_b1")),
+                (1..2, vec![27], Some("rule_15")),
+                (2..2, vec![60], Some("rule_40")),
+                (2..3, vec![11], Some("rule_40")),
+                (3..4, vec![8], Some("rule_1")),
+                (3..4, vec![21], Some("rule_6")),
+            ]
+        );
+        assert_eq!(
+            explain("아예 A."),
+            vec![
+                (0..1, vec![35], Some("rule_6")),
+                (1..1, vec![36], Some("rule_11")),
+                (1..2, vec![12], Some("rule_7")),
+                (2..3, vec![0], None),
+                (3..3, vec![52], Some("rule_29")),
+                (3..3, vec![32], Some("rule_28")),
+                (3..4, vec![1], Some("rule_28")),
+                (4..4, vec![50], Some("rule_29")),
+                (4..5, vec![50], None),
+            ]
+        );
+        assert_eq!(
+            explain("그래서"),
+            vec![(0..3, vec![1, 14], Some("rule_18"))]
+        );
+        assert_eq!(
+            explain("e.g.는"),
+            vec![
+                (0..0, vec![52], Some("rule_29")),
+                (0..1, vec![17], Some("rule_28")),
+                (1..2, vec![50], Some("rule_30")),
+                (2..3, vec![27], Some("rule_28")),
+                (3..3, vec![50], Some("rule_29")),
+                (3..4, vec![50], None),
+                (4..5, vec![9], Some("rule_1")),
+                (4..5, vec![53], Some("rule_15")),
+            ]
+        );
+        // 약어가 아닌 로마자 문장 끝의 마침표는 28항의 ｢통일영어점자 규정｣대로 적습니다.
+        assert!(explain("Go. Stop 가").contains(&(2..3, vec![50], Some("rule_28"))));
+        assert!(explain("Mr. Kim 가").contains(&(2..3, vec![50], Some("rule_30"))));
+        assert!(explain("U.S.A. 가").contains(&(3..4, vec![50], Some("rule_30"))));
+
+        // 모든 테스트 케이스에서 규정 ID 가 rule_map.json 에 있어야 한다
+        let rule_map: HashMap<String, HashMap<String, String>> = serde_json::from_str(
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../rule_map.json")),
+        )
+        .unwrap();
+        for (input, expected) in test_cases() {
+            let (_, spans) = encode_recorded(&input).unwrap();
+            for id in spans.rule_ids() {
+                assert!(rule_map.contains_key(id), "{input}: {id}");
+            }
+            let encoding = encode_explained(&input).unwrap();
+            assert_eq!(encoding.cells, expected, "{input}");
+            assert_eq!(
+                encoding.spans.last().map_or(0, |span| span.cells.end),
+                encoding.cells.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_with_options() {
+        let options = EncodeOptions::new();
+        assert_eq!(encode_with_options("abc", &options).unwrap(), vec![1, 3, 9]);
+        assert_eq!(
+            encode_with_options("abc", &options.clone().context(Context::Korean)).unwrap(),
+            vec![52, 1, 3, 9]
+        );
+        assert_eq!(
+            encode_with_options("가 abc", &options.clone().context(Context::English)).unwrap(),
+            vec![43, 0, 1, 3, 9]
+        );
+        assert_eq!(
+            encode_with_options("the child", &options).unwrap(),
+            vec![46, 0, 33, 10, 7, 25]
+        );
+        assert_eq!(
+            encode_with_options("the child", &options.clone().english_contractions(false)).unwrap(),
+            vec![30, 19, 17, 0, 9, 19, 10, 7, 25]
+        );
+        assert_eq!(
+            encode_with_options("그래서 억 가", &options.clone().korean_contractions(false))
+                .unwrap(),
+            vec![8, 42, 16, 23, 32, 14, 0, 14, 1, 0, 8, 35]
+        );
+        assert_eq!(
+            encode_with_options("가\n나", &options).unwrap(),
+            vec![43, 255, 9]
+        );
+        assert_eq!(
+            encode_with_options("가\n나", &options.clone().newline(Newline::Space)).unwrap(),
+            vec![43, 0, 9]
+        );
+        assert_eq!(
+            encode_with_options(
+                "가😀나 😀 다",
+                &options.clone().unsupported(Unsupported::Skip)
+            )
+            .unwrap(),
+            vec![43, 9, 0, 10]
+        );
+        assert_eq!(
+            encode_with_options(
+                "가😀나 😀 다",
+                &options
+                    .clone()
+                    .unsupported(Unsupported::Replace(BrailleCell::new(63).unwrap()))
+            )
+            .unwrap(),
+            vec![43, 63, 9, 0, 63, 0, 10]
+        );
+        assert_eq!(
+            encode_to_unicode_with_options("가", &options.korean_contractions(false)).unwrap(),
+            "⠈⠣"
+        );
+    }
+
+    #[test]
+    fn test_rule_options() {
+        let options = EncodeOptions::new();
+        assert_eq!(
+            encode_with_options("성 가", &options).unwrap(),
+            vec![32, 59, 0, 43]
+        );
+        // 17항만 끄면 ‘성’ 은 풀어 쓰고 13항 약자 ‘가’ 는 그대로 씁니다.
+        assert_eq!(
+            encode_with_options("성 가", &options.clone().disable_rule("rule_17")).unwrap(),
+            vec![32, 14, 54, 0, 43]
+        );
+        assert_eq!(
+            encode_with_options(
+                "성 가",
+                &options
+                    .clone()
+                    .korean_contractions(false)
+                    .enable_rule("rule_17")
+            )
+            .unwrap(),
+            vec![32, 59, 0, 8, 35]
+        );
+        assert_eq!(
+            encode_with_options("아예", &options.clone().disable_rule("rule_11")).unwrap(),
+            vec![35, 12]
+        );
+
+        struct Space;
+        impl KoreanRule for Space {
+            fn id(&self) -> &'static str {
+                "custom"
+            }
+            fn phase(&self) -> RulePhase {
+                RulePhase::After
+            }
+            fn encode(
+                &self,
+                context: &SyllableContext,
+                result: &mut Vec<u8>,
+            ) -> Result<bool, BrailleError> {
+                if context.next() == Some('나') {
+                    result.push(0);
+                    return Ok(true);
+                }
+                Ok(false)
+            }
+        }
+        let options = options.rule(std::sync::Arc::new(Space));
+        assert_eq!(
+            encode_with_options("가나", &options).unwrap(),
+            vec![43, 0, 9]
+        );
+        // rule_map.json 에 없는 규정은 설명 없이 남습니다.
+        let mut encoder = encoder_for("가나", &options);
+        encoder.spans = Some(SpanRecorder::default());
+        encoder.encode("가나", &mut Vec::new()).unwrap();
+        let spans = encoder.spans.take().unwrap().into_explained();
+        assert_eq!(spans.len(), 3);
+        assert!(spans[1].rule.is_none());
+
+        // 18항 약어는 단어 점역에 들어 있으므로 같은 ID 의 규정은 약어 대신 음절마다 적용됩니다.
+        struct Syllables;
+        impl KoreanRule for Syllables {
+            fn id(&self) -> &'static str {
+                "rule_18"
+            }
+            fn encode(&self, _: &SyllableContext, _: &mut Vec<u8>) -> Result<bool, BrailleError> {
+                Ok(false)
+            }
+        }
+        assert_eq!(
+            encode_with_options(
+                "그래서",
+                &EncodeOptions::new().rule(std::sync::Arc::new(Syllables))
+            )
+            .unwrap(),
+            encode_with_options("그래서", &EncodeOptions::new().disable_rule("rule_18")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encode_error() {
+        assert_eq!(
+            encode("안녕 😀").unwrap_err(),
+            BrailleError::UnsupportedCharacter {
+                character: '😀',
+                offset: Offset::new(7, 3),
+            }
+        );
+        // 약어 뒤에 이어지는 글자도 원문 기준의 위치를 가진다
+        assert_eq!(
+            encode("가 그래서😀").unwrap_err().offset(),
+            Some(Offset::new(13, 5))
+        );
+        // 앞 글자를 처리하기 전에 점역할 수 없는 문자를 찾는다
+        assert_eq!(
+            encode("아😀").unwrap_err().offset(),
+            Some(Offset::new(3, 1))
+        );
+        assert_eq!(
+            decode(&[1, 64]).unwrap_err(),
+            BrailleError::InvalidBrailleCell { cell: 64, index: 1 }
+        );
+        assert_eq!(
+            decode_from_unicode("⠁a").unwrap_err(),
+            BrailleError::InvalidBrailleCharacter {
+                character: 'a',
+                offset: Offset::new(3, 1),
+            }
+        );
+    }
+
+    #[test]
+    fn test_encode_normalized() {
+        // 첫가끝 자모로 풀어 쓴 한글과 전각, 반각 글자는 보통 글자와 같게 점역한다
+        let nfd = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}";
+        assert_eq!(encode(nfd).unwrap(), encode("한글").unwrap());
+        assert_eq!(
+            encode("ＡＢＣ１２３！").unwrap(),
+            encode("ABC123!").unwrap()
+        );
+        assert_eq!(encode("ﾡ\u{3000}ﾤ").unwrap(), encode("ㄱ ㄴ").unwrap());
+        assert!(required_len(nfd) >= encode(nfd).unwrap().len());
+
+        // 끄면 입력을 그대로 점역한다
+        let options = EncodeOptions::new().normalize(false);
+        assert!(encode_with_options("ＡＢＣ", &options).is_err());
+        assert_ne!(
+            encode_with_options(nfd, &options).unwrap(),
+            encode(nfd).unwrap()
+        );
+
+        // 오류 위치와 글자 범위는 원문 기준이다
+        let text = "\u{1100}\u{1161}\u{11AB} 😀";
+        assert_eq!(encode(text).unwrap_err().offset(), Some(Offset::new(10, 4)));
+        let encoding = encode_with_spans("\u{1100}\u{1161}\u{11AB}나").unwrap();
+        assert_eq!(encoding.spans[0].source, 0..3);
+        assert_eq!(encoding.spans.last().unwrap().source, 3..4);
+    }
+
+    #[test]
+    fn test_encode_to_braille_font() {
+        assert_eq!(encode_to_braille_font("두더지").unwrap(), "imis.o");
+        assert_eq!(encode_to_braille_font("너비").unwrap(), "cs^o");
+        assert_eq!(encode_to_braille_font("거리").unwrap(), "`s\"o");
+        assert_eq!(
+            encode_to_braille_font("안녕\n반가워").unwrap(),
+            "<3c]\n^3$p"
+        );
+        assert_eq!(encode_to_brf("안녕\n반가워").unwrap(), "<3c]\r\n^3$p\r\n");
+        assert_eq!(encode_to_brf("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_encode_to_tokens() {
+        let cell = |dots: &[u8]| BrailleToken::Cell(BrailleCell::from_dots(dots).unwrap());
+        assert_eq!(
+            encode_to_tokens("가 나\n").unwrap(),
+            vec![
+                cell(&[1, 2, 4, 6]),
+                BrailleToken::Space,
+                cell(&[1, 4]),
+                BrailleToken::LineBreak,
+            ]
+        );
+        assert_eq!(
+            encode_to_tokens_with_options(
+                "가😀",
+                &EncodeOptions::new().unsupported(Unsupported::Replace(BrailleCell::EMPTY))
+            )
+            .unwrap(),
+            vec![cell(&[1, 2, 4, 6]), BrailleToken::Space]
+        );
+    }
+
+    #[test]
+    fn test_encode_to_sink() {
+        let options = EncodeOptions::default();
+        let mut dots = DotSink::new();
+        encode_to_sink("가나 다\n라", &options, &mut dots).unwrap();
+        assert_eq!(dots.as_str(), "1246-14 24\n5-126");
+
+        for text in ["상상이상의 ", "Table of Contents", "ABC DEF GHI 가"] {
+            let mut cells = Vec::<u8>::new();
+            encode_to_sink(text, &EncodeOptions::default(), &mut cells).unwrap();
+            assert_eq!(cells, encode(text).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_encode_into() {
+        let text = "안녕하세요 ABC 123";
+        let expected = encode(text).unwrap();
+        let mut buffer = vec![0; required_len(text)];
+        let len = encode_into(text, &mut buffer).unwrap();
+        assert_eq!(&buffer[..len], expected);
+
+        let mut buffer = [0; 4];
+        assert_eq!(
+            encode_into(text, &mut buffer).unwrap_err(),
+            BrailleError::BufferTooSmall {
+                required: expected.len(),
+                capacity: 4
+            }
+        );
+        assert_eq!(buffer, expected[..4]);
+        assert_eq!(encode_into("", &mut []), Ok(0));
+
+        for (input, expected) in test_cases() {
+            assert!(required_len(&input) >= expected.len(), "{input}");
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_required_len_proptest(s in "[가-힣a-zA-Z0-9ㄱ-ㅣ .,!?()+=\n-]{0,40}") {
+            if let Ok(encoded) = encode(&s) {
+                assert!(required_len(&s) >= encoded.len());
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let spanned = encode_with_spans("가a").unwrap();
+        let json = serde_json::to_string(&spanned).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SpannedEncoding>(&json).unwrap(),
+            spanned
+        );
+
+        let explained = encode_explained("성").unwrap();
+        let json = serde_json::to_value(&explained).unwrap();
+        assert_eq!(json["spans"][0]["rule"]["id"], "rule_17");
+        assert_eq!(
+            serde_json::from_value::<ExplainedEncoding>(json).unwrap(),
+            explained
+        );
+
+        let error = encode("가😀").unwrap_err();
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "UnsupportedCharacter": {"character": "😀", "offset": {"byte": 3, "char": 1}}
+            })
+        );
+        assert_eq!(serde_json::from_value::<BrailleError>(json).unwrap(), error);
+
+        let cell = BrailleCell::new(19).unwrap();
+        assert_eq!(serde_json::to_string(&cell).unwrap(), "19");
+        assert!(serde_json::from_str::<BrailleCell>("64").is_err());
+        assert_eq!(
+            serde_json::from_str::<Vec<BrailleToken>>(r#"[{"Cell":19},"Space","LineBreak"]"#)
+                .unwrap(),
+            vec![
+                BrailleToken::Cell(cell),
+                BrailleToken::Space,
+                BrailleToken::LineBreak
+            ]
+        );
+
+        // 빠진 항목은 기본값입니다.
+        let options = EncodeOptions::new()
+            .context(Context::English)
+            .unsupported(Unsupported::Replace(BrailleCell::new(63).unwrap()))
+            .disable_rule("rule_11");
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            serde_json::from_str::<EncodeOptions>(&json).unwrap(),
+            options
+        );
+        assert_eq!(
+            serde_json::from_str::<EncodeOptions>(r#"{"newline": "Space"}"#).unwrap(),
+            EncodeOptions::new().newline(Newline::Space)
+        );
+        // 6점 범위를 벗어난 대체 셀은 읽지 않습니다.
+        assert!(
+            serde_json::from_str::<EncodeOptions>(r#"{"unsupported": {"Replace": 200}}"#).is_err()
+        );
+        assert_eq!(
+            serde_json::from_str::<LayoutOptions>(r#"{"line_width": 20}"#).unwrap(),
+            LayoutOptions::new().line_width(20)
+        );
+        assert_eq!(
+            serde_json::from_str::<PefOptions>("{}").unwrap(),
+            PefOptions::default()
+        );
+        // 빌더와 같이 줄 수와 칸 수의 최솟값을 지킵니다.
+        let layout =
+            serde_json::from_str::<LayoutOptions>(r#"{"line_width": 0, "lines_per_page": 0}"#)
+                .unwrap();
+        assert_eq!(layout, LayoutOptions::new().line_width(0).lines_per_page(0));
+        assert_eq!(
+            paginate(&encode_with_spans("가나").unwrap(), &layout)[0].lines,
+            vec![encode("가나").unwrap()]
+        );
+        assert_eq!(
+            serde_json::from_str::<PefOptions>(r#"{"cells_per_row": 1, "rows_per_page": 0}"#)
+                .unwrap(),
+            PefOptions::default().cells_per_row(1).rows_per_page(0)
+        );
+    }
+
     #[test]
     fn test_encoder_streaming() {
         // Test encoder can be reused
@@ -789,5 +2019,13 @@ mod test {
         // Should produce same result as one-shot
         let expected = encode("testing").unwrap();
         assert_eq!(buffer, expected);
+
+        // 이어진 단어 뒤의 단어는 첫머리 약자를 씁니다.
+        let mut encoder = Encoder::new(false);
+        let mut buffer = Vec::new();
+        encoder.encode("test", &mut buffer).unwrap();
+        encoder.encode("ing ing", &mut buffer).unwrap();
+        encoder.finish(&mut buffer).unwrap();
+        assert_eq!(buffer, encode("testing ing").unwrap());
     }
 }
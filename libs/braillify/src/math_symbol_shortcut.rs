@@ -1,6 +1,6 @@
 use phf::phf_map;
 
-use crate::unicode::decode_unicode;
+use crate::{error::BrailleError, unicode::decode_unicode, utils::longest_match};
 
 static SHORTCUT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     '+' => &[decode_unicode('⠢')],
@@ -12,14 +12,18 @@ static SHORTCUT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     '<' => &[decode_unicode('⠔'),decode_unicode('⠔')],
 };
 
-pub fn encode_char_math_symbol_shortcut(text: char) -> Result<&'static [u8], String> {
+pub fn encode_char_math_symbol_shortcut(text: char) -> Result<&'static [u8], BrailleError> {
     if let Some(code) = SHORTCUT_MAP.get(&text) {
         Ok(code)
     } else {
-        Err("Invalid math symbol character".to_string())
+        Err(BrailleError::invalid_symbol(text))
     }
 }
 
+pub fn decode_char_math_symbol_shortcut(code: &[u8]) -> Option<(char, usize)> {
+    longest_match(SHORTCUT_MAP.entries().map(|(k, v)| (*k, *v)), code)
+}
+
 pub fn is_math_symbol_char(text: char) -> bool {
     SHORTCUT_MAP.contains_key(&text)
 }
@@ -72,7 +76,7 @@ mod test {
         );
         assert_eq!(
             encode_char_math_symbol_shortcut('a').unwrap_err(),
-            "Invalid math symbol character"
+            BrailleError::invalid_symbol('a')
         );
     }
 }
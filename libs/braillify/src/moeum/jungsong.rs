@@ -1,4 +1,4 @@
-use crate::unicode::decode_unicode;
+use crate::{error::BrailleError, unicode::decode_unicode, utils::longest_match};
 
 use phf::phf_map;
 
@@ -25,14 +25,18 @@ pub static JUNGSEONG_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     'ㅙ' => &[decode_unicode('⠧'), decode_unicode('⠗')],
     'ㅞ' => &[decode_unicode('⠏'), decode_unicode('⠗')],
 };
-pub fn encode_jungsong(text: char) -> Result<&'static [u8], String> {
+pub fn encode_jungsong(text: char) -> Result<&'static [u8], BrailleError> {
     if let Some(code) = JUNGSEONG_MAP.get(&text) {
         Ok(code)
     } else {
-        Err("Invalid Korean jungseong character".to_string())
+        Err(BrailleError::invalid_jamo(text))
     }
 }
 
+pub fn decode_jungsong(code: &[u8]) -> Option<(char, usize)> {
+    longest_match(JUNGSEONG_MAP.entries().map(|(k, v)| (*k, *v)), code)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -48,4 +52,14 @@ mod test {
         assert_eq!(encode_jungsong('ㅛ').unwrap(), vec![decode_unicode('⠬')]);
         assert_eq!(encode_jungsong('ㅜ').unwrap(), vec![decode_unicode('⠍')]);
     }
+
+    #[test]
+    pub fn test_decode_jungsong() {
+        assert_eq!(decode_jungsong(&[decode_unicode('⠣')]), Some(('ㅏ', 1)));
+        assert_eq!(
+            decode_jungsong(&[decode_unicode('⠍'), decode_unicode('⠗')]),
+            Some(('ㅟ', 2))
+        );
+        assert_eq!(decode_jungsong(&[decode_unicode('⠁')]), None);
+    }
 }
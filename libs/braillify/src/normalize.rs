@@ -0,0 +1,179 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{ops::RangeInclusive, str::Chars};
+
+use crate::error::Offset;
+
+/// 현대 한글 초성, 중성, 종성 첫가끝 자모
+const CHOSEONG: RangeInclusive<char> = '\u{1100}'..='\u{1112}';
+const JUNGSEONG: RangeInclusive<char> = '\u{1161}'..='\u{1175}';
+const JONGSEONG: RangeInclusive<char> = '\u{11A8}'..='\u{11C2}';
+
+/// 전각 로마자, 숫자, 기호와 반각 한글을 보통 글자로 바꿉니다.
+pub(crate) fn fold_width(c: char) -> char {
+    let code = c as u32;
+    let folded = match c {
+        '\u{3000}' => 0x20,
+        '\u{FF01}'..='\u{FF5E}' => code - 0xFEE0,
+        // 반각 한글은 호환 자모와 순서가 같고 중간에 빈 자리가 있습니다.
+        '\u{FFA1}'..='\u{FFBE}' => code - 0xFFA1 + 0x3131,
+        '\u{FFC2}'..='\u{FFC7}' => code - 0xFFC2 + 0x314F,
+        '\u{FFCA}'..='\u{FFCF}' => code - 0xFFCA + 0x3155,
+        '\u{FFD2}'..='\u{FFD7}' => code - 0xFFD2 + 0x315B,
+        '\u{FFDA}'..='\u{FFDC}' => code - 0xFFDA + 0x3161,
+        '\u{FFE0}' => 0xA2,
+        '\u{FFE1}' => 0xA3,
+        '\u{FFE2}' => 0xAC,
+        '\u{FFE3}' => 0xAF,
+        '\u{FFE4}' => 0xA6,
+        '\u{FFE5}' => 0xA5,
+        '\u{FFE6}' => 0x20A9,
+        _ => return c,
+    };
+    char::from_u32(folded).unwrap_or(c)
+}
+
+/// 중성이나 종성이 더 이어지면 옛한글 음절이므로 현대 음절로 합치지 않습니다.
+fn continues_syllable(c: Option<char>) -> bool {
+    matches!(c, Some('\u{1160}'..='\u{11FF}' | '\u{D7B0}'..='\u{D7FF}'))
+}
+
+/// 받침 없는 현대 한글 음절인지 확인합니다.
+fn is_open_syllable(c: char) -> bool {
+    ('\u{AC00}'..='\u{D7A3}').contains(&c) && (c as u32 - 0xAC00).is_multiple_of(28)
+}
+
+/// `first` 부터 이어지는 첫가끝 자모를 현대 한글 음절 하나로 합칩니다.
+///
+/// 합쳤으면 `rest` 를 합친 자모 뒤로 옮기고 (음절, 쓴 글자 수) 를 돌려줍니다.
+fn compose<I: Iterator<Item = char> + Clone>(first: char, rest: &mut I) -> Option<(char, usize)> {
+    let mut ahead = rest.clone();
+    let (syllable, mut used) = if CHOSEONG.contains(&first) {
+        let jung = ahead.next().filter(|jung| JUNGSEONG.contains(jung))?;
+        let index = (first as u32 - 0x1100) * 21 + (jung as u32 - 0x1161);
+        (0xAC00 + index * 28, 2)
+    } else if is_open_syllable(first) {
+        (first as u32, 1)
+    } else {
+        return None;
+    };
+
+    let mut after = ahead.clone();
+    let syllable = match after.next() {
+        Some(jong) if JONGSEONG.contains(&jong) && !continues_syllable(after.clone().next()) => {
+            ahead = after;
+            used += 1;
+            syllable + (jong as u32 - 0x11A7)
+        }
+        next if used > 1 && !continues_syllable(next) => syllable,
+        _ => return None,
+    };
+    *rest = ahead;
+    char::from_u32(syllable).map(|syllable| (syllable, used))
+}
+
+/// 정규화한 글자와 그 글자가 차지하던 원문 글자 수
+struct NormalizedChars<'a> {
+    chars: core::iter::Map<Chars<'a>, fn(char) -> char>,
+}
+
+impl Iterator for NormalizedChars<'_> {
+    type Item = (char, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.chars.next()?;
+        Some(compose(first, &mut self.chars).unwrap_or((first, 1)))
+    }
+}
+
+fn normalized_chars(text: &str) -> NormalizedChars<'_> {
+    NormalizedChars {
+        chars: text.chars().map(fold_width as fn(char) -> char),
+    }
+}
+
+fn needs_normalization(text: &str) -> bool {
+    text.chars()
+        .any(|c| fold_width(c) != c || ('\u{1100}'..='\u{11FF}').contains(&c))
+}
+
+/// 같은 문장이 유니코드 표현과 관계없이 같은 점자가 되도록 입력을 정규화합니다.
+///
+/// - 첫가끝 자모로 풀어 쓴 현대 한글(NFD, 예: U+1100 U+1161 U+11AB)을 음절 ‘간’ 으로 합칩니다.
+///   옛한글 자모가 섞인 음절은 그대로 둡니다.
+/// - 전각 로마자, 숫자, 기호(Ａ, １, ！)와 전각 빈칸, 반각 한글(ﾡ)을 보통 글자로 바꿉니다.
+pub(crate) fn normalize(text: &str) -> Cow<'_, str> {
+    if !needs_normalization(text) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(normalized_chars(text).map(|(c, _)| c).collect::<String>())
+}
+
+/// 정규화한 글자마다 원문에서의 글자 위치, 마지막에 원문 글자 수를 덧붙입니다.
+pub(crate) fn origins(text: &str) -> Vec<usize> {
+    let mut origin = 0;
+    let mut origins = Vec::with_capacity(text.len() + 1);
+    for (_, used) in normalized_chars(text) {
+        origins.push(origin);
+        origin += used;
+    }
+    origins.push(origin);
+    origins
+}
+
+/// 정규화한 문자열 기준의 위치를 원문 기준으로 옮깁니다.
+pub(crate) fn original_offset(text: &str, offset: Offset) -> Offset {
+    let origins = origins(text);
+    let char = origins
+        .get(offset.char)
+        .copied()
+        .unwrap_or(origins[origins.len() - 1]);
+    Offset::of_char(text, char)
+}
+
+/// 정규화한 글자, `required_len` 이 칸 수를 어림할 때 씁니다.
+pub(crate) fn normalized(text: &str) -> impl Iterator<Item = char> + '_ {
+    normalized_chars(text).map(|(c, _)| c)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_normalize() {
+        assert!(matches!(normalize("안녕하세요"), Cow::Borrowed(_)));
+        assert_eq!(normalize("\u{1100}\u{1161}\u{11AB}"), "간");
+        assert_eq!(
+            normalize("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"),
+            "한글"
+        );
+        assert_eq!(normalize("가\u{11A8}"), "각");
+        assert_eq!(
+            normalize("\u{110B}\u{1161}\u{1102}\u{1167}\u{11BC}"),
+            "아녕"
+        );
+        // 옛한글 음절은 합치지 않습니다.
+        assert_eq!(normalize("\u{1107}\u{119E}"), "\u{1107}\u{119E}");
+        assert_eq!(
+            normalize("\u{1100}\u{1161}\u{11EB}"),
+            "\u{1100}\u{1161}\u{11EB}"
+        );
+        assert_eq!(
+            normalize("\u{1100}\u{1161}\u{11A8}\u{11BA}"),
+            "\u{1100}\u{1161}\u{11A8}\u{11BA}"
+        );
+        assert_eq!(normalize("\u{1100}"), "\u{1100}");
+        assert_eq!(
+            normalize("Ａｂｃ１２３！\u{3000}ﾡﾢ\u{FFC2}"),
+            "Abc123! ㄱㄲㅏ"
+        );
+        assert_eq!(normalize("\u{FFDC}\u{FFE6}"), "ㅣ₩");
+    }
+
+    #[test]
+    pub fn test_original_offset() {
+        let text = "\u{1100}\u{1161}\u{11AB}Ａ😀";
+        assert_eq!(origins(text), vec![0, 3, 4, 5]);
+        assert_eq!(original_offset(text, Offset::new(4, 2)), Offset::new(12, 4));
+    }
+}
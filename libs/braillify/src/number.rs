@@ -1,6 +1,6 @@
 use phf::phf_map;
 
-use crate::unicode::decode_unicode;
+use crate::{error::BrailleError, unicode::decode_unicode};
 // 1 2 3 4 5 6 7 8 9 0
 // #a #b #c #d #e #f #g #h
 
@@ -17,9 +17,16 @@ pub static NUMBER_MAP: phf::Map<char, u8> = phf_map! {
     '0' => decode_unicode('⠚'),
 };
 
-pub fn encode_number(text: char) -> Result<u8, String> {
+pub fn encode_number(text: char) -> Result<u8, BrailleError> {
     if let Some(code) = NUMBER_MAP.get(&text) {
         return Ok(*code);
     }
-    Err("Invalid number character".to_string())
+    Err(BrailleError::invalid_number(text))
+}
+
+pub fn decode_number(code: u8) -> Option<char> {
+    NUMBER_MAP
+        .entries()
+        .find(|(_, value)| **value == code)
+        .map(|(key, _)| *key)
 }
@@ -0,0 +1,485 @@
+use phf::phf_map;
+
+use crate::{error::BrailleError, unicode::decode_unicode};
+
+/// 첫가끝 자모(초성, 중성, 종성으로 나뉜 한글 자모)의 점형
+///
+/// 초성은 1, 2항, 중성은 6, 7항, 종성은 3, 4, 5항의 점형으로 적고, 첫소리 ‘ㅇ’ 은 적지 않습니다.
+/// 옛 자음자 ‘ㅿ, ㆁ, ㆆ’ 과 입술가벼운소리 ‘ㅸ, ㅹ, ㅱ, ㆄ’, 옛 모음자 ‘ㆍ’ 는 ⠸ 을 앞세워
+/// 비슷한 현대 글자로 적으며(ㆍ 는 ⠸⠂), 병서와 겹모음은 각 글자를 어울러 적습니다.
+/// 치두음, 정치음 구별은 적지 않습니다.
+static JAMO_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    // 초성
+    '\u{1100}' => &[decode_unicode('⠈')],
+    '\u{1101}' => &[decode_unicode('⠠'), decode_unicode('⠈')],
+    '\u{1102}' => &[decode_unicode('⠉')],
+    '\u{1103}' => &[decode_unicode('⠊')],
+    '\u{1104}' => &[decode_unicode('⠠'), decode_unicode('⠊')],
+    '\u{1105}' => &[decode_unicode('⠐')],
+    '\u{1106}' => &[decode_unicode('⠑')],
+    '\u{1107}' => &[decode_unicode('⠘')],
+    '\u{1108}' => &[decode_unicode('⠠'), decode_unicode('⠘')],
+    '\u{1109}' => &[decode_unicode('⠠')],
+    '\u{110A}' => &[decode_unicode('⠠'), decode_unicode('⠠')],
+    '\u{110B}' => &[],
+    '\u{110C}' => &[decode_unicode('⠨')],
+    '\u{110D}' => &[decode_unicode('⠠'), decode_unicode('⠨')],
+    '\u{110E}' => &[decode_unicode('⠰')],
+    '\u{110F}' => &[decode_unicode('⠋')],
+    '\u{1110}' => &[decode_unicode('⠓')],
+    '\u{1111}' => &[decode_unicode('⠙')],
+    '\u{1112}' => &[decode_unicode('⠚')],
+    '\u{1113}' => &[decode_unicode('⠉'), decode_unicode('⠈')],
+    '\u{1114}' => &[decode_unicode('⠠'), decode_unicode('⠉')],
+    '\u{1115}' => &[decode_unicode('⠉'), decode_unicode('⠊')],
+    '\u{1116}' => &[decode_unicode('⠉'), decode_unicode('⠘')],
+    '\u{1117}' => &[decode_unicode('⠊'), decode_unicode('⠈')],
+    '\u{1118}' => &[decode_unicode('⠐'), decode_unicode('⠉')],
+    '\u{1119}' => &[decode_unicode('⠠'), decode_unicode('⠐')],
+    '\u{111A}' => &[decode_unicode('⠐'), decode_unicode('⠚')],
+    '\u{111B}' => &[decode_unicode('⠸'), decode_unicode('⠐')],
+    '\u{111C}' => &[decode_unicode('⠑'), decode_unicode('⠘')],
+    '\u{111D}' => &[decode_unicode('⠸'), decode_unicode('⠑')],
+    '\u{111E}' => &[decode_unicode('⠘'), decode_unicode('⠈')],
+    '\u{111F}' => &[decode_unicode('⠘'), decode_unicode('⠉')],
+    '\u{1120}' => &[decode_unicode('⠘'), decode_unicode('⠊')],
+    '\u{1121}' => &[decode_unicode('⠘'), decode_unicode('⠠')],
+    '\u{1122}' => &[decode_unicode('⠘'), decode_unicode('⠠'), decode_unicode('⠈')],
+    '\u{1123}' => &[decode_unicode('⠘'), decode_unicode('⠠'), decode_unicode('⠊')],
+    '\u{1124}' => &[decode_unicode('⠘'), decode_unicode('⠠'), decode_unicode('⠘')],
+    '\u{1125}' => &[decode_unicode('⠘'), decode_unicode('⠠'), decode_unicode('⠠')],
+    '\u{1126}' => &[decode_unicode('⠘'), decode_unicode('⠠'), decode_unicode('⠨')],
+    '\u{1127}' => &[decode_unicode('⠘'), decode_unicode('⠨')],
+    '\u{1128}' => &[decode_unicode('⠘'), decode_unicode('⠰')],
+    '\u{1129}' => &[decode_unicode('⠘'), decode_unicode('⠓')],
+    '\u{112A}' => &[decode_unicode('⠘'), decode_unicode('⠙')],
+    '\u{112B}' => &[decode_unicode('⠸'), decode_unicode('⠘')],
+    '\u{112C}' => &[decode_unicode('⠸'), decode_unicode('⠠'), decode_unicode('⠘')],
+    '\u{112D}' => &[decode_unicode('⠠'), decode_unicode('⠈')],
+    '\u{112E}' => &[decode_unicode('⠠'), decode_unicode('⠉')],
+    '\u{112F}' => &[decode_unicode('⠠'), decode_unicode('⠊')],
+    '\u{1130}' => &[decode_unicode('⠠'), decode_unicode('⠐')],
+    '\u{1131}' => &[decode_unicode('⠠'), decode_unicode('⠑')],
+    '\u{1132}' => &[decode_unicode('⠠'), decode_unicode('⠘')],
+    '\u{1133}' => &[decode_unicode('⠠'), decode_unicode('⠘'), decode_unicode('⠈')],
+    '\u{1134}' => &[decode_unicode('⠠'), decode_unicode('⠠'), decode_unicode('⠠')],
+    '\u{1135}' => &[decode_unicode('⠠'), decode_unicode('⠶')],
+    '\u{1136}' => &[decode_unicode('⠠'), decode_unicode('⠨')],
+    '\u{1137}' => &[decode_unicode('⠠'), decode_unicode('⠰')],
+    '\u{1138}' => &[decode_unicode('⠠'), decode_unicode('⠋')],
+    '\u{1139}' => &[decode_unicode('⠠'), decode_unicode('⠓')],
+    '\u{113A}' => &[decode_unicode('⠠'), decode_unicode('⠙')],
+    '\u{113B}' => &[decode_unicode('⠠'), decode_unicode('⠚')],
+    '\u{113C}' => &[decode_unicode('⠠')],
+    '\u{113D}' => &[decode_unicode('⠠'), decode_unicode('⠠')],
+    '\u{113E}' => &[decode_unicode('⠠')],
+    '\u{113F}' => &[decode_unicode('⠠'), decode_unicode('⠠')],
+    '\u{1140}' => &[decode_unicode('⠸'), decode_unicode('⠠')],
+    '\u{1141}' => &[decode_unicode('⠶'), decode_unicode('⠈')],
+    '\u{1142}' => &[decode_unicode('⠶'), decode_unicode('⠊')],
+    '\u{1143}' => &[decode_unicode('⠶'), decode_unicode('⠑')],
+    '\u{1144}' => &[decode_unicode('⠶'), decode_unicode('⠘')],
+    '\u{1145}' => &[decode_unicode('⠶'), decode_unicode('⠠')],
+    '\u{1146}' => &[decode_unicode('⠶'), decode_unicode('⠸'), decode_unicode('⠠')],
+    '\u{1147}' => &[decode_unicode('⠠'), decode_unicode('⠶')],
+    '\u{1148}' => &[decode_unicode('⠶'), decode_unicode('⠨')],
+    '\u{1149}' => &[decode_unicode('⠶'), decode_unicode('⠰')],
+    '\u{114A}' => &[decode_unicode('⠶'), decode_unicode('⠓')],
+    '\u{114B}' => &[decode_unicode('⠶'), decode_unicode('⠙')],
+    '\u{114C}' => &[decode_unicode('⠸'), decode_unicode('⠶')],
+    '\u{114D}' => &[decode_unicode('⠨'), decode_unicode('⠶')],
+    '\u{114E}' => &[decode_unicode('⠨')],
+    '\u{114F}' => &[decode_unicode('⠠'), decode_unicode('⠨')],
+    '\u{1150}' => &[decode_unicode('⠨')],
+    '\u{1151}' => &[decode_unicode('⠠'), decode_unicode('⠨')],
+    '\u{1152}' => &[decode_unicode('⠰'), decode_unicode('⠋')],
+    '\u{1153}' => &[decode_unicode('⠰'), decode_unicode('⠚')],
+    '\u{1154}' => &[decode_unicode('⠰')],
+    '\u{1155}' => &[decode_unicode('⠰')],
+    '\u{1156}' => &[decode_unicode('⠙'), decode_unicode('⠘')],
+    '\u{1157}' => &[decode_unicode('⠸'), decode_unicode('⠙')],
+    '\u{1158}' => &[decode_unicode('⠠'), decode_unicode('⠚')],
+    '\u{1159}' => &[decode_unicode('⠸'), decode_unicode('⠚')],
+    '\u{115A}' => &[decode_unicode('⠈'), decode_unicode('⠊')],
+    '\u{115B}' => &[decode_unicode('⠉'), decode_unicode('⠠')],
+    '\u{115C}' => &[decode_unicode('⠉'), decode_unicode('⠨')],
+    '\u{115D}' => &[decode_unicode('⠉'), decode_unicode('⠚')],
+    '\u{115E}' => &[decode_unicode('⠊'), decode_unicode('⠐')],
+    '\u{115F}' => &[],
+    // 중성 (U+1160 은 중성 채움 문자)
+    '\u{1160}' => &[],
+    '\u{1161}' => &[decode_unicode('⠣')],
+    '\u{1162}' => &[decode_unicode('⠗')],
+    '\u{1163}' => &[decode_unicode('⠜')],
+    '\u{1164}' => &[decode_unicode('⠜'), decode_unicode('⠗')],
+    '\u{1165}' => &[decode_unicode('⠎')],
+    '\u{1166}' => &[decode_unicode('⠝')],
+    '\u{1167}' => &[decode_unicode('⠱')],
+    '\u{1168}' => &[decode_unicode('⠌')],
+    '\u{1169}' => &[decode_unicode('⠥')],
+    '\u{116A}' => &[decode_unicode('⠧')],
+    '\u{116B}' => &[decode_unicode('⠧'), decode_unicode('⠗')],
+    '\u{116C}' => &[decode_unicode('⠽')],
+    '\u{116D}' => &[decode_unicode('⠬')],
+    '\u{116E}' => &[decode_unicode('⠍')],
+    '\u{116F}' => &[decode_unicode('⠏')],
+    '\u{1170}' => &[decode_unicode('⠏'), decode_unicode('⠗')],
+    '\u{1171}' => &[decode_unicode('⠍'), decode_unicode('⠗')],
+    '\u{1172}' => &[decode_unicode('⠩')],
+    '\u{1173}' => &[decode_unicode('⠪')],
+    '\u{1174}' => &[decode_unicode('⠺')],
+    '\u{1175}' => &[decode_unicode('⠕')],
+    '\u{1176}' => &[decode_unicode('⠣'), decode_unicode('⠥')],
+    '\u{1177}' => &[decode_unicode('⠣'), decode_unicode('⠍')],
+    '\u{1178}' => &[decode_unicode('⠜'), decode_unicode('⠥')],
+    '\u{1179}' => &[decode_unicode('⠜'), decode_unicode('⠬')],
+    '\u{117A}' => &[decode_unicode('⠎'), decode_unicode('⠥')],
+    '\u{117B}' => &[decode_unicode('⠎'), decode_unicode('⠍')],
+    '\u{117C}' => &[decode_unicode('⠎'), decode_unicode('⠪')],
+    '\u{117D}' => &[decode_unicode('⠱'), decode_unicode('⠥')],
+    '\u{117E}' => &[decode_unicode('⠱'), decode_unicode('⠍')],
+    '\u{117F}' => &[decode_unicode('⠥'), decode_unicode('⠎')],
+    '\u{1180}' => &[decode_unicode('⠥'), decode_unicode('⠝')],
+    '\u{1181}' => &[decode_unicode('⠥'), decode_unicode('⠌')],
+    '\u{1182}' => &[decode_unicode('⠥'), decode_unicode('⠥')],
+    '\u{1183}' => &[decode_unicode('⠥'), decode_unicode('⠍')],
+    '\u{1184}' => &[decode_unicode('⠬'), decode_unicode('⠜')],
+    '\u{1185}' => &[decode_unicode('⠬'), decode_unicode('⠜'), decode_unicode('⠗')],
+    '\u{1186}' => &[decode_unicode('⠬'), decode_unicode('⠱')],
+    '\u{1187}' => &[decode_unicode('⠬'), decode_unicode('⠥')],
+    '\u{1188}' => &[decode_unicode('⠬'), decode_unicode('⠕')],
+    '\u{1189}' => &[decode_unicode('⠍'), decode_unicode('⠣')],
+    '\u{118A}' => &[decode_unicode('⠍'), decode_unicode('⠗')],
+    '\u{118B}' => &[decode_unicode('⠍'), decode_unicode('⠎'), decode_unicode('⠪')],
+    '\u{118C}' => &[decode_unicode('⠍'), decode_unicode('⠌')],
+    '\u{118D}' => &[decode_unicode('⠍'), decode_unicode('⠍')],
+    '\u{118E}' => &[decode_unicode('⠩'), decode_unicode('⠣')],
+    '\u{118F}' => &[decode_unicode('⠩'), decode_unicode('⠎')],
+    '\u{1190}' => &[decode_unicode('⠩'), decode_unicode('⠝')],
+    '\u{1191}' => &[decode_unicode('⠩'), decode_unicode('⠱')],
+    '\u{1192}' => &[decode_unicode('⠩'), decode_unicode('⠌')],
+    '\u{1193}' => &[decode_unicode('⠩'), decode_unicode('⠍')],
+    '\u{1194}' => &[decode_unicode('⠩'), decode_unicode('⠕')],
+    '\u{1195}' => &[decode_unicode('⠪'), decode_unicode('⠍')],
+    '\u{1196}' => &[decode_unicode('⠪'), decode_unicode('⠪')],
+    '\u{1197}' => &[decode_unicode('⠺'), decode_unicode('⠍')],
+    '\u{1198}' => &[decode_unicode('⠕'), decode_unicode('⠣')],
+    '\u{1199}' => &[decode_unicode('⠕'), decode_unicode('⠜')],
+    '\u{119A}' => &[decode_unicode('⠕'), decode_unicode('⠥')],
+    '\u{119B}' => &[decode_unicode('⠕'), decode_unicode('⠍')],
+    '\u{119C}' => &[decode_unicode('⠕'), decode_unicode('⠪')],
+    '\u{119D}' => &[decode_unicode('⠕'), decode_unicode('⠸'), decode_unicode('⠂')],
+    '\u{119E}' => &[decode_unicode('⠸'), decode_unicode('⠂')],
+    '\u{119F}' => &[decode_unicode('⠸'), decode_unicode('⠂'), decode_unicode('⠎')],
+    '\u{11A0}' => &[decode_unicode('⠸'), decode_unicode('⠂'), decode_unicode('⠍')],
+    '\u{11A1}' => &[decode_unicode('⠸'), decode_unicode('⠂'), decode_unicode('⠕')],
+    '\u{11A2}' => &[decode_unicode('⠸'), decode_unicode('⠂'), decode_unicode('⠸'), decode_unicode('⠂')],
+    '\u{11A3}' => &[decode_unicode('⠣'), decode_unicode('⠪')],
+    '\u{11A4}' => &[decode_unicode('⠜'), decode_unicode('⠍')],
+    '\u{11A5}' => &[decode_unicode('⠱'), decode_unicode('⠜')],
+    '\u{11A6}' => &[decode_unicode('⠥'), decode_unicode('⠜')],
+    '\u{11A7}' => &[decode_unicode('⠥'), decode_unicode('⠜'), decode_unicode('⠗')],
+    // 종성
+    '\u{11A8}' => &[decode_unicode('⠁')],
+    '\u{11A9}' => &[decode_unicode('⠁'), decode_unicode('⠁')],
+    '\u{11AA}' => &[decode_unicode('⠁'), decode_unicode('⠄')],
+    '\u{11AB}' => &[decode_unicode('⠒')],
+    '\u{11AC}' => &[decode_unicode('⠒'), decode_unicode('⠅')],
+    '\u{11AD}' => &[decode_unicode('⠒'), decode_unicode('⠴')],
+    '\u{11AE}' => &[decode_unicode('⠔')],
+    '\u{11AF}' => &[decode_unicode('⠂')],
+    '\u{11B0}' => &[decode_unicode('⠂'), decode_unicode('⠁')],
+    '\u{11B1}' => &[decode_unicode('⠂'), decode_unicode('⠢')],
+    '\u{11B2}' => &[decode_unicode('⠂'), decode_unicode('⠃')],
+    '\u{11B3}' => &[decode_unicode('⠂'), decode_unicode('⠄')],
+    '\u{11B4}' => &[decode_unicode('⠂'), decode_unicode('⠦')],
+    '\u{11B5}' => &[decode_unicode('⠂'), decode_unicode('⠲')],
+    '\u{11B6}' => &[decode_unicode('⠂'), decode_unicode('⠴')],
+    '\u{11B7}' => &[decode_unicode('⠢')],
+    '\u{11B8}' => &[decode_unicode('⠃')],
+    '\u{11B9}' => &[decode_unicode('⠃'), decode_unicode('⠄')],
+    '\u{11BA}' => &[decode_unicode('⠄')],
+    '\u{11BB}' => &[decode_unicode('⠌')],
+    '\u{11BC}' => &[decode_unicode('⠶')],
+    '\u{11BD}' => &[decode_unicode('⠅')],
+    '\u{11BE}' => &[decode_unicode('⠆')],
+    '\u{11BF}' => &[decode_unicode('⠖')],
+    '\u{11C0}' => &[decode_unicode('⠦')],
+    '\u{11C1}' => &[decode_unicode('⠲')],
+    '\u{11C2}' => &[decode_unicode('⠴')],
+    '\u{11C3}' => &[decode_unicode('⠁'), decode_unicode('⠂')],
+    '\u{11C4}' => &[decode_unicode('⠁'), decode_unicode('⠄'), decode_unicode('⠁')],
+    '\u{11C5}' => &[decode_unicode('⠒'), decode_unicode('⠁')],
+    '\u{11C6}' => &[decode_unicode('⠒'), decode_unicode('⠔')],
+    '\u{11C7}' => &[decode_unicode('⠒'), decode_unicode('⠄')],
+    '\u{11C8}' => &[decode_unicode('⠒'), decode_unicode('⠸'), decode_unicode('⠄')],
+    '\u{11C9}' => &[decode_unicode('⠒'), decode_unicode('⠦')],
+    '\u{11CA}' => &[decode_unicode('⠔'), decode_unicode('⠁')],
+    '\u{11CB}' => &[decode_unicode('⠔'), decode_unicode('⠂')],
+    '\u{11CC}' => &[decode_unicode('⠂'), decode_unicode('⠁'), decode_unicode('⠄')],
+    '\u{11CD}' => &[decode_unicode('⠂'), decode_unicode('⠒')],
+    '\u{11CE}' => &[decode_unicode('⠂'), decode_unicode('⠔')],
+    '\u{11CF}' => &[decode_unicode('⠂'), decode_unicode('⠔'), decode_unicode('⠴')],
+    '\u{11D0}' => &[decode_unicode('⠂'), decode_unicode('⠂')],
+    '\u{11D1}' => &[decode_unicode('⠂'), decode_unicode('⠢'), decode_unicode('⠁')],
+    '\u{11D2}' => &[decode_unicode('⠂'), decode_unicode('⠢'), decode_unicode('⠄')],
+    '\u{11D3}' => &[decode_unicode('⠂'), decode_unicode('⠃'), decode_unicode('⠄')],
+    '\u{11D4}' => &[decode_unicode('⠂'), decode_unicode('⠃'), decode_unicode('⠴')],
+    '\u{11D5}' => &[decode_unicode('⠂'), decode_unicode('⠸'), decode_unicode('⠃')],
+    '\u{11D6}' => &[decode_unicode('⠂'), decode_unicode('⠄'), decode_unicode('⠄')],
+    '\u{11D7}' => &[decode_unicode('⠂'), decode_unicode('⠸'), decode_unicode('⠄')],
+    '\u{11D8}' => &[decode_unicode('⠂'), decode_unicode('⠖')],
+    '\u{11D9}' => &[decode_unicode('⠂'), decode_unicode('⠸'), decode_unicode('⠴')],
+    '\u{11DA}' => &[decode_unicode('⠢'), decode_unicode('⠁')],
+    '\u{11DB}' => &[decode_unicode('⠢'), decode_unicode('⠂')],
+    '\u{11DC}' => &[decode_unicode('⠢'), decode_unicode('⠃')],
+    '\u{11DD}' => &[decode_unicode('⠢'), decode_unicode('⠄')],
+    '\u{11DE}' => &[decode_unicode('⠢'), decode_unicode('⠄'), decode_unicode('⠄')],
+    '\u{11DF}' => &[decode_unicode('⠢'), decode_unicode('⠸'), decode_unicode('⠄')],
+    '\u{11E0}' => &[decode_unicode('⠢'), decode_unicode('⠆')],
+    '\u{11E1}' => &[decode_unicode('⠢'), decode_unicode('⠴')],
+    '\u{11E2}' => &[decode_unicode('⠸'), decode_unicode('⠢')],
+    '\u{11E3}' => &[decode_unicode('⠃'), decode_unicode('⠂')],
+    '\u{11E4}' => &[decode_unicode('⠃'), decode_unicode('⠲')],
+    '\u{11E5}' => &[decode_unicode('⠃'), decode_unicode('⠴')],
+    '\u{11E6}' => &[decode_unicode('⠸'), decode_unicode('⠃')],
+    '\u{11E7}' => &[decode_unicode('⠄'), decode_unicode('⠁')],
+    '\u{11E8}' => &[decode_unicode('⠄'), decode_unicode('⠔')],
+    '\u{11E9}' => &[decode_unicode('⠄'), decode_unicode('⠂')],
+    '\u{11EA}' => &[decode_unicode('⠄'), decode_unicode('⠃')],
+    '\u{11EB}' => &[decode_unicode('⠸'), decode_unicode('⠄')],
+    '\u{11EC}' => &[decode_unicode('⠶'), decode_unicode('⠁')],
+    '\u{11ED}' => &[decode_unicode('⠶'), decode_unicode('⠁'), decode_unicode('⠁')],
+    '\u{11EE}' => &[decode_unicode('⠶'), decode_unicode('⠶')],
+    '\u{11EF}' => &[decode_unicode('⠶'), decode_unicode('⠖')],
+    '\u{11F0}' => &[decode_unicode('⠸'), decode_unicode('⠶')],
+    '\u{11F1}' => &[decode_unicode('⠸'), decode_unicode('⠶'), decode_unicode('⠄')],
+    '\u{11F2}' => &[decode_unicode('⠸'), decode_unicode('⠶'), decode_unicode('⠸'), decode_unicode('⠄')],
+    '\u{11F3}' => &[decode_unicode('⠲'), decode_unicode('⠃')],
+    '\u{11F4}' => &[decode_unicode('⠸'), decode_unicode('⠲')],
+    '\u{11F5}' => &[decode_unicode('⠴'), decode_unicode('⠒')],
+    '\u{11F6}' => &[decode_unicode('⠴'), decode_unicode('⠂')],
+    '\u{11F7}' => &[decode_unicode('⠴'), decode_unicode('⠢')],
+    '\u{11F8}' => &[decode_unicode('⠴'), decode_unicode('⠃')],
+    '\u{11F9}' => &[decode_unicode('⠸'), decode_unicode('⠴')],
+    '\u{11FA}' => &[decode_unicode('⠁'), decode_unicode('⠒')],
+    '\u{11FB}' => &[decode_unicode('⠁'), decode_unicode('⠃')],
+    '\u{11FC}' => &[decode_unicode('⠁'), decode_unicode('⠆')],
+    '\u{11FD}' => &[decode_unicode('⠁'), decode_unicode('⠖')],
+    '\u{11FE}' => &[decode_unicode('⠁'), decode_unicode('⠴')],
+    '\u{11FF}' => &[decode_unicode('⠒'), decode_unicode('⠒')],
+    // 확장 초성
+    '\u{A960}' => &[decode_unicode('⠊'), decode_unicode('⠑')],
+    '\u{A961}' => &[decode_unicode('⠊'), decode_unicode('⠘')],
+    '\u{A962}' => &[decode_unicode('⠊'), decode_unicode('⠠')],
+    '\u{A963}' => &[decode_unicode('⠊'), decode_unicode('⠨')],
+    '\u{A964}' => &[decode_unicode('⠐'), decode_unicode('⠈')],
+    '\u{A965}' => &[decode_unicode('⠐'), decode_unicode('⠠'), decode_unicode('⠈')],
+    '\u{A966}' => &[decode_unicode('⠐'), decode_unicode('⠊')],
+    '\u{A967}' => &[decode_unicode('⠐'), decode_unicode('⠠'), decode_unicode('⠊')],
+    '\u{A968}' => &[decode_unicode('⠐'), decode_unicode('⠑')],
+    '\u{A969}' => &[decode_unicode('⠐'), decode_unicode('⠘')],
+    '\u{A96A}' => &[decode_unicode('⠐'), decode_unicode('⠠'), decode_unicode('⠘')],
+    '\u{A96B}' => &[decode_unicode('⠐'), decode_unicode('⠸'), decode_unicode('⠘')],
+    '\u{A96C}' => &[decode_unicode('⠐'), decode_unicode('⠠')],
+    '\u{A96D}' => &[decode_unicode('⠐'), decode_unicode('⠨')],
+    '\u{A96E}' => &[decode_unicode('⠐'), decode_unicode('⠋')],
+    '\u{A96F}' => &[decode_unicode('⠑'), decode_unicode('⠈')],
+    '\u{A970}' => &[decode_unicode('⠑'), decode_unicode('⠊')],
+    '\u{A971}' => &[decode_unicode('⠑'), decode_unicode('⠠')],
+    '\u{A972}' => &[decode_unicode('⠘'), decode_unicode('⠠'), decode_unicode('⠓')],
+    '\u{A973}' => &[decode_unicode('⠘'), decode_unicode('⠋')],
+    '\u{A974}' => &[decode_unicode('⠘'), decode_unicode('⠚')],
+    '\u{A975}' => &[decode_unicode('⠠'), decode_unicode('⠠'), decode_unicode('⠘')],
+    '\u{A976}' => &[decode_unicode('⠶'), decode_unicode('⠐')],
+    '\u{A977}' => &[decode_unicode('⠶'), decode_unicode('⠚')],
+    '\u{A978}' => &[decode_unicode('⠠'), decode_unicode('⠨'), decode_unicode('⠚')],
+    '\u{A979}' => &[decode_unicode('⠠'), decode_unicode('⠓')],
+    '\u{A97A}' => &[decode_unicode('⠙'), decode_unicode('⠚')],
+    '\u{A97B}' => &[decode_unicode('⠚'), decode_unicode('⠠')],
+    '\u{A97C}' => &[decode_unicode('⠠'), decode_unicode('⠸'), decode_unicode('⠚')],
+    // 확장 중성
+    '\u{D7B0}' => &[decode_unicode('⠥'), decode_unicode('⠱')],
+    '\u{D7B1}' => &[decode_unicode('⠥'), decode_unicode('⠥'), decode_unicode('⠕')],
+    '\u{D7B2}' => &[decode_unicode('⠬'), decode_unicode('⠣')],
+    '\u{D7B3}' => &[decode_unicode('⠬'), decode_unicode('⠗')],
+    '\u{D7B4}' => &[decode_unicode('⠬'), decode_unicode('⠎')],
+    '\u{D7B5}' => &[decode_unicode('⠍'), decode_unicode('⠱')],
+    '\u{D7B6}' => &[decode_unicode('⠍'), decode_unicode('⠕'), decode_unicode('⠕')],
+    '\u{D7B7}' => &[decode_unicode('⠩'), decode_unicode('⠗')],
+    '\u{D7B8}' => &[decode_unicode('⠩'), decode_unicode('⠥')],
+    '\u{D7B9}' => &[decode_unicode('⠪'), decode_unicode('⠣')],
+    '\u{D7BA}' => &[decode_unicode('⠪'), decode_unicode('⠎')],
+    '\u{D7BB}' => &[decode_unicode('⠪'), decode_unicode('⠝')],
+    '\u{D7BC}' => &[decode_unicode('⠪'), decode_unicode('⠥')],
+    '\u{D7BD}' => &[decode_unicode('⠕'), decode_unicode('⠜'), decode_unicode('⠥')],
+    '\u{D7BE}' => &[decode_unicode('⠕'), decode_unicode('⠜'), decode_unicode('⠗')],
+    '\u{D7BF}' => &[decode_unicode('⠕'), decode_unicode('⠱')],
+    '\u{D7C0}' => &[decode_unicode('⠕'), decode_unicode('⠌')],
+    '\u{D7C1}' => &[decode_unicode('⠕'), decode_unicode('⠥'), decode_unicode('⠕')],
+    '\u{D7C2}' => &[decode_unicode('⠕'), decode_unicode('⠬')],
+    '\u{D7C3}' => &[decode_unicode('⠕'), decode_unicode('⠩')],
+    '\u{D7C4}' => &[decode_unicode('⠕'), decode_unicode('⠕')],
+    '\u{D7C5}' => &[decode_unicode('⠸'), decode_unicode('⠂'), decode_unicode('⠣')],
+    '\u{D7C6}' => &[decode_unicode('⠸'), decode_unicode('⠂'), decode_unicode('⠝')],
+    // 확장 종성
+    '\u{D7CB}' => &[decode_unicode('⠒'), decode_unicode('⠂')],
+    '\u{D7CC}' => &[decode_unicode('⠒'), decode_unicode('⠆')],
+    '\u{D7CD}' => &[decode_unicode('⠔'), decode_unicode('⠔')],
+    '\u{D7CE}' => &[decode_unicode('⠔'), decode_unicode('⠔'), decode_unicode('⠃')],
+    '\u{D7CF}' => &[decode_unicode('⠔'), decode_unicode('⠃')],
+    '\u{D7D0}' => &[decode_unicode('⠔'), decode_unicode('⠄')],
+    '\u{D7D1}' => &[decode_unicode('⠔'), decode_unicode('⠄'), decode_unicode('⠁')],
+    '\u{D7D2}' => &[decode_unicode('⠔'), decode_unicode('⠅')],
+    '\u{D7D3}' => &[decode_unicode('⠔'), decode_unicode('⠆')],
+    '\u{D7D4}' => &[decode_unicode('⠔'), decode_unicode('⠦')],
+    '\u{D7D5}' => &[decode_unicode('⠂'), decode_unicode('⠁'), decode_unicode('⠁')],
+    '\u{D7D6}' => &[decode_unicode('⠂'), decode_unicode('⠁'), decode_unicode('⠴')],
+    '\u{D7D7}' => &[decode_unicode('⠂'), decode_unicode('⠂'), decode_unicode('⠖')],
+    '\u{D7D8}' => &[decode_unicode('⠂'), decode_unicode('⠢'), decode_unicode('⠴')],
+    '\u{D7D9}' => &[decode_unicode('⠂'), decode_unicode('⠃'), decode_unicode('⠔')],
+    '\u{D7DA}' => &[decode_unicode('⠂'), decode_unicode('⠃'), decode_unicode('⠲')],
+    '\u{D7DB}' => &[decode_unicode('⠂'), decode_unicode('⠸'), decode_unicode('⠶')],
+    '\u{D7DC}' => &[decode_unicode('⠂'), decode_unicode('⠸'), decode_unicode('⠴'), decode_unicode('⠴')],
+    '\u{D7DD}' => &[decode_unicode('⠸'), decode_unicode('⠂')],
+    '\u{D7DE}' => &[decode_unicode('⠢'), decode_unicode('⠒')],
+    '\u{D7DF}' => &[decode_unicode('⠢'), decode_unicode('⠒'), decode_unicode('⠒')],
+    '\u{D7E0}' => &[decode_unicode('⠢'), decode_unicode('⠢')],
+    '\u{D7E1}' => &[decode_unicode('⠢'), decode_unicode('⠃'), decode_unicode('⠄')],
+    '\u{D7E2}' => &[decode_unicode('⠢'), decode_unicode('⠅')],
+    '\u{D7E3}' => &[decode_unicode('⠃'), decode_unicode('⠔')],
+    '\u{D7E4}' => &[decode_unicode('⠃'), decode_unicode('⠂'), decode_unicode('⠲')],
+    '\u{D7E5}' => &[decode_unicode('⠃'), decode_unicode('⠢')],
+    '\u{D7E6}' => &[decode_unicode('⠃'), decode_unicode('⠃')],
+    '\u{D7E7}' => &[decode_unicode('⠃'), decode_unicode('⠄'), decode_unicode('⠔')],
+    '\u{D7E8}' => &[decode_unicode('⠃'), decode_unicode('⠅')],
+    '\u{D7E9}' => &[decode_unicode('⠃'), decode_unicode('⠆')],
+    '\u{D7EA}' => &[decode_unicode('⠄'), decode_unicode('⠢')],
+    '\u{D7EB}' => &[decode_unicode('⠄'), decode_unicode('⠸'), decode_unicode('⠃')],
+    '\u{D7EC}' => &[decode_unicode('⠄'), decode_unicode('⠄'), decode_unicode('⠁')],
+    '\u{D7ED}' => &[decode_unicode('⠄'), decode_unicode('⠄'), decode_unicode('⠔')],
+    '\u{D7EE}' => &[decode_unicode('⠄'), decode_unicode('⠸'), decode_unicode('⠄')],
+    '\u{D7EF}' => &[decode_unicode('⠄'), decode_unicode('⠅')],
+    '\u{D7F0}' => &[decode_unicode('⠄'), decode_unicode('⠆')],
+    '\u{D7F1}' => &[decode_unicode('⠄'), decode_unicode('⠦')],
+    '\u{D7F2}' => &[decode_unicode('⠄'), decode_unicode('⠴')],
+    '\u{D7F3}' => &[decode_unicode('⠸'), decode_unicode('⠄'), decode_unicode('⠃')],
+    '\u{D7F4}' => &[decode_unicode('⠸'), decode_unicode('⠄'), decode_unicode('⠸'), decode_unicode('⠃')],
+    '\u{D7F5}' => &[decode_unicode('⠸'), decode_unicode('⠶'), decode_unicode('⠢')],
+    '\u{D7F6}' => &[decode_unicode('⠸'), decode_unicode('⠶'), decode_unicode('⠴')],
+    '\u{D7F7}' => &[decode_unicode('⠅'), decode_unicode('⠃')],
+    '\u{D7F8}' => &[decode_unicode('⠅'), decode_unicode('⠃'), decode_unicode('⠃')],
+    '\u{D7F9}' => &[decode_unicode('⠅'), decode_unicode('⠅')],
+    '\u{D7FA}' => &[decode_unicode('⠲'), decode_unicode('⠄')],
+    '\u{D7FB}' => &[decode_unicode('⠲'), decode_unicode('⠦')],
+};
+
+/// 단독으로 쓰인 옛한글 호환 자모의 점형, 자음자는 받침으로 적습니다. (8항)
+static COMPAT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    'ㅥ' => &[decode_unicode('⠒'), decode_unicode('⠒')],
+    'ㅦ' => &[decode_unicode('⠒'), decode_unicode('⠔')],
+    'ㅧ' => &[decode_unicode('⠒'), decode_unicode('⠄')],
+    'ㅨ' => &[decode_unicode('⠒'), decode_unicode('⠸'), decode_unicode('⠄')],
+    'ㅩ' => &[decode_unicode('⠂'), decode_unicode('⠁'), decode_unicode('⠄')],
+    'ㅪ' => &[decode_unicode('⠂'), decode_unicode('⠔')],
+    'ㅫ' => &[decode_unicode('⠂'), decode_unicode('⠃'), decode_unicode('⠄')],
+    'ㅬ' => &[decode_unicode('⠂'), decode_unicode('⠸'), decode_unicode('⠄')],
+    'ㅭ' => &[decode_unicode('⠂'), decode_unicode('⠸'), decode_unicode('⠴')],
+    'ㅮ' => &[decode_unicode('⠢'), decode_unicode('⠃')],
+    'ㅯ' => &[decode_unicode('⠢'), decode_unicode('⠄')],
+    'ㅰ' => &[decode_unicode('⠢'), decode_unicode('⠸'), decode_unicode('⠄')],
+    'ㅱ' => &[decode_unicode('⠸'), decode_unicode('⠢')],
+    'ㅲ' => &[decode_unicode('⠃'), decode_unicode('⠁')],
+    'ㅳ' => &[decode_unicode('⠃'), decode_unicode('⠔')],
+    'ㅴ' => &[decode_unicode('⠃'), decode_unicode('⠄'), decode_unicode('⠁')],
+    'ㅵ' => &[decode_unicode('⠃'), decode_unicode('⠄'), decode_unicode('⠔')],
+    'ㅶ' => &[decode_unicode('⠃'), decode_unicode('⠅')],
+    'ㅷ' => &[decode_unicode('⠃'), decode_unicode('⠦')],
+    'ㅸ' => &[decode_unicode('⠸'), decode_unicode('⠃')],
+    'ㅹ' => &[decode_unicode('⠸'), decode_unicode('⠃'), decode_unicode('⠃')],
+    'ㅺ' => &[decode_unicode('⠄'), decode_unicode('⠁')],
+    'ㅻ' => &[decode_unicode('⠄'), decode_unicode('⠒')],
+    'ㅼ' => &[decode_unicode('⠄'), decode_unicode('⠔')],
+    'ㅽ' => &[decode_unicode('⠄'), decode_unicode('⠃')],
+    'ㅾ' => &[decode_unicode('⠄'), decode_unicode('⠅')],
+    'ㅿ' => &[decode_unicode('⠸'), decode_unicode('⠄')],
+    'ㆀ' => &[decode_unicode('⠶'), decode_unicode('⠶')],
+    'ㆁ' => &[decode_unicode('⠸'), decode_unicode('⠶')],
+    'ㆂ' => &[decode_unicode('⠸'), decode_unicode('⠶'), decode_unicode('⠄')],
+    'ㆃ' => &[decode_unicode('⠸'), decode_unicode('⠶'), decode_unicode('⠸'), decode_unicode('⠄')],
+    'ㆄ' => &[decode_unicode('⠸'), decode_unicode('⠲')],
+    'ㆅ' => &[decode_unicode('⠴'), decode_unicode('⠴')],
+    'ㆆ' => &[decode_unicode('⠸'), decode_unicode('⠴')],
+    'ㆇ' => &[decode_unicode('⠬'), decode_unicode('⠜')],
+    'ㆈ' => &[decode_unicode('⠬'), decode_unicode('⠜'), decode_unicode('⠗')],
+    'ㆉ' => &[decode_unicode('⠬'), decode_unicode('⠕')],
+    'ㆊ' => &[decode_unicode('⠩'), decode_unicode('⠱')],
+    'ㆋ' => &[decode_unicode('⠩'), decode_unicode('⠌')],
+    'ㆌ' => &[decode_unicode('⠩'), decode_unicode('⠕')],
+    'ㆍ' => &[decode_unicode('⠸'), decode_unicode('⠂')],
+    'ㆎ' => &[decode_unicode('⠸'), decode_unicode('⠂'), decode_unicode('⠕')],
+};
+
+/// 첫가끝 자모인지 확인합니다. 옛한글 음절은 이 자모를 이어 적어 나타냅니다.
+pub fn is_old_hangul_char(c: char) -> bool {
+    matches!(c, '\u{1100}'..='\u{11FF}' | '\u{A960}'..='\u{A97C}' | '\u{D7B0}'..='\u{D7FB}')
+}
+
+/// 숫자 뒤에서 숫자와 혼동되는 첫소리 ‘ㄴ, ㄷ, ㅁ, ㅋ, ㅌ, ㅍ, ㅎ’의 첫가끝 자모인지 확인합니다. (44항)
+pub fn is_number_like_choseong(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1102}' | '\u{1103}' | '\u{1106}' | '\u{110F}' | '\u{1110}' | '\u{1111}' | '\u{1112}'
+    )
+}
+
+/// 옛한글 호환 자모(U+3165..U+318E)인지 확인합니다.
+pub fn is_old_hangul_part(c: char) -> bool {
+    COMPAT_MAP.contains_key(&c)
+}
+
+/// 옛한글 음절은 약자를 쓰지 않고 첫가끝 자모를 하나씩 풀어 적습니다.
+pub fn encode_old_hangul(c: char) -> Result<&'static [u8], BrailleError> {
+    JAMO_MAP
+        .get(&c)
+        .copied()
+        .ok_or(BrailleError::invalid_jamo(c))
+}
+
+/// 단독으로 쓰인 옛한글 호환 자모 (8항)
+pub fn encode_old_hangul_part(c: char) -> Option<&'static [u8]> {
+    COMPAT_MAP.get(&c).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_encode_old_hangul() {
+        // ᄀ ᅡ ᆨ 은 ‘각’ 을 약자 없이 적은 것과 같습니다.
+        assert_eq!(encode_old_hangul('\u{1100}').unwrap(), &[8]);
+        assert_eq!(encode_old_hangul('\u{1161}').unwrap(), &[35]);
+        assert_eq!(encode_old_hangul('\u{11A8}').unwrap(), &[1]);
+        assert!(encode_old_hangul('\u{110B}').unwrap().is_empty());
+        // ㅿ, ㆁ, ㆆ, ㆍ
+        assert_eq!(encode_old_hangul('\u{1140}').unwrap(), &[56, 32]);
+        assert_eq!(encode_old_hangul('\u{114C}').unwrap(), &[56, 54]);
+        assert_eq!(encode_old_hangul('\u{1159}').unwrap(), &[56, 26]);
+        assert_eq!(encode_old_hangul('\u{119E}').unwrap(), &[56, 2]);
+        // ᄢ (ㅂ, ㅅ, ㄱ 합용병서), ᄫ (입술가벼운소리), ᇌ (ㄹ, ㄱ, ㅅ 받침)
+        assert_eq!(encode_old_hangul('\u{1122}').unwrap(), &[24, 32, 8]);
+        assert_eq!(encode_old_hangul('\u{112B}').unwrap(), &[56, 24]);
+        assert_eq!(encode_old_hangul('\u{11CC}').unwrap(), &[2, 1, 4]);
+        assert!(encode_old_hangul('가').is_err());
+
+        assert_eq!(encode_old_hangul_part('ㆍ'), Some(&[56, 2][..]));
+        assert_eq!(encode_old_hangul_part('ㅿ'), Some(&[56, 4][..]));
+        assert_eq!(encode_old_hangul_part('ㄱ'), None);
+        assert!(is_old_hangul_part('ㆆ') && !is_old_hangul_part('ㅎ'));
+        for c in ('\u{1100}'..='\u{11FF}').chain('\u{A960}'..='\u{A97C}') {
+            assert!(is_old_hangul_char(c));
+            assert!(encode_old_hangul(c).is_ok(), "{c:?}");
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use crate::{
+    cell::BrailleCell,
+    old_hangul::{is_old_hangul_char, is_old_hangul_part},
+    rule::{CONTRACTION_RULES, KoreanRule, RuleOverride, RuleSet},
+};
+
+/// 로마자를 국어 문장 안의 로마자로 볼지, 영어 문장으로 볼지 정합니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Context {
+    /// 한글이 하나라도 있으면 국어 문장으로 봅니다.
+    ///
+    /// `Encoder` 는 문장 전체를 미리 볼 수 없으므로 `Korean` 과 같이 동작합니다.
+    #[default]
+    Auto,
+    /// 국어 문장, 로마자 앞뒤에 로마자표 ⠴ 와 로마자 종료표 ⠲ 를 적습니다. (29항)
+    Korean,
+    /// 영어 문장, 로마자표를 적지 않습니다.
+    English,
+}
+
+/// 줄바꿈 문자(`\n`)를 점역하는 방법
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Newline {
+    /// 줄바꿈 셀(255)로 남깁니다.
+    #[default]
+    Keep,
+    /// 빈칸(0)으로 바꿉니다.
+    Space,
+}
+
+/// 점역할 수 없는 문자를 만났을 때의 처리 방법
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Unsupported {
+    /// `BrailleError::UnsupportedCharacter` 를 돌려줍니다.
+    #[default]
+    Error,
+    /// 문자를 건너뜁니다.
+    Skip,
+    /// 문자를 주어진 셀로 바꿉니다. 점이 없는 칸(`BrailleCell::EMPTY`)은 빈칸과 같습니다.
+    Replace(BrailleCell),
+}
+
+/// 점역 옵션
+///
+/// ```
+/// use braillify::{Context, EncodeOptions, encode_with_options};
+///
+/// let options = EncodeOptions::new()
+///     .context(Context::Korean)
+///     .korean_contractions(false);
+/// assert_eq!(encode_with_options("가", &options).unwrap(), vec![8, 35]);
+/// ```
+///
+/// `serde` 로 저장할 때 `rule` 로 더한 사용자 규정은 빠지며, 빠진 항목은 기본값으로 읽습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct EncodeOptions {
+    pub(crate) context: Context,
+    pub(crate) english_contractions: bool,
+    pub(crate) newline: Newline,
+    pub(crate) unsupported: Unsupported,
+    pub(crate) normalize: bool,
+    /// 끈 규정 ID
+    pub(crate) disabled_rules: BTreeSet<String>,
+    /// 기본 규정을 바꾸는 사용자 규정
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) rules: Vec<RuleOverride>,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        Self {
+            context: Context::Auto,
+            english_contractions: true,
+            newline: Newline::Keep,
+            unsupported: Unsupported::Error,
+            normalize: true,
+            disabled_rules: BTreeSet::new(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl EncodeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 풀어쓰기: 한글 약자와 약어를 쓰지 않고 모든 글자를 초성, 중성, 종성으로 적습니다.
+    ///
+    /// 받침 ‘ㅆ’의 ⠌ (4항) 처럼 자모 자체의 점형은 그대로 씁니다.
+    pub fn uncontracted() -> Self {
+        Self::default().korean_contractions(false)
+    }
+
+    /// 국어 문장, 영어 문장 여부 (기본값 `Context::Auto`)
+    pub fn context(mut self, context: Context) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// 한글 약자(13~17항)와 약어(18항) 사용 여부 (기본값 true)
+    ///
+    /// `rule_13`, `rule_15`, `rule_16`, `rule_17`, `rule_18` 을 한꺼번에 켜고 끕니다.
+    pub fn korean_contractions(self, enabled: bool) -> Self {
+        CONTRACTION_RULES.into_iter().fold(self, |options, id| {
+            if enabled {
+                options.enable_rule(id)
+            } else {
+                options.disable_rule(id)
+            }
+        })
+    }
+
+    /// `rule_map.json` 의 ID 로 규정 하나를 끕니다. (예: 13항 약자는 쓰고 17항만 끄기)
+    ///
+    /// 끌 수 있는 규정은 4항 ‘겄’, 11, 12항 구분표, 13~17항 약자, 18항 약어입니다.
+    /// 1~10항의 자모 점형은 끌 수 없습니다.
+    pub fn disable_rule(mut self, id: &str) -> Self {
+        self.disabled_rules.insert(id.to_string());
+        self
+    }
+
+    /// `disable_rule` 로 끈 규정을 다시 켭니다.
+    pub fn enable_rule(mut self, id: &str) -> Self {
+        self.disabled_rules.remove(id);
+        self
+    }
+
+    /// 같은 ID 의 기본 규정을 `rule` 로 바꿉니다. 같은 ID 의 기본 규정이 없으면 덧붙입니다.
+    ///
+    /// 13, 15, 18항은 `KoreanRule` 로 구현되어 있지 않으므로 기본 규정을 끄고 `rule` 을 음절마다
+    /// 적용합니다.
+    pub fn rule(mut self, rule: Arc<dyn KoreanRule>) -> Self {
+        self.rules
+            .retain(|RuleOverride(other)| other.id() != rule.id());
+        self.rules.push(RuleOverride(rule));
+        self
+    }
+
+    pub(crate) fn rule_set(&self) -> RuleSet {
+        RuleSet::new(&self.disabled_rules, &self.rules)
+    }
+
+    /// 통일영어점자 약자 사용 여부 (기본값 true)
+    pub fn english_contractions(mut self, enabled: bool) -> Self {
+        self.english_contractions = enabled;
+        self
+    }
+
+    /// 줄바꿈 처리 방법 (기본값 `Newline::Keep`)
+    pub fn newline(mut self, newline: Newline) -> Self {
+        self.newline = newline;
+        self
+    }
+
+    /// 점역하기 전에 입력을 정규화할지 여부 (기본값 true)
+    ///
+    /// 첫가끝 자모로 풀어 쓴 한글(NFD)을 음절로 합치고, 전각 로마자, 숫자, 기호와 반각 한글을
+    /// 보통 글자로 바꿉니다. 오류와 글자-셀 대응의 위치는 정규화하기 전의 원문 기준입니다.
+    pub fn normalize(mut self, enabled: bool) -> Self {
+        self.normalize = enabled;
+        self
+    }
+
+    /// 점역할 수 없는 문자의 처리 방법 (기본값 `Unsupported::Error`)
+    pub fn unsupported(mut self, unsupported: Unsupported) -> Self {
+        self.unsupported = unsupported;
+        self
+    }
+
+    /// 로마자표를 적을지 여부, `Context::Auto` 이면 `text` 에 한글이 있는지로 정합니다.
+    pub(crate) fn english_indicator(&self, text: &str) -> bool {
+        match self.context {
+            Context::Auto => has_korean(text),
+            Context::Korean => true,
+            Context::English => false,
+        }
+    }
+}
+
+/// 한국어가 존재할 경우 english_indicator 가 true 가 됩니다.
+pub(crate) fn has_korean(text: &str) -> bool {
+    text.split(' ').filter(|word| !word.is_empty()).any(|word| {
+        word.chars().any(|c| {
+            (c as u32 >= 0x3131 && c as u32 <= 0x3163)
+                || (0xAC00 <= c as u32 && c as u32 <= 0xD7A3)
+                || is_old_hangul_char(c)
+                || is_old_hangul_part(c)
+                // 반각 한글
+                || ('\u{FFA1}'..='\u{FFDC}').contains(&c)
+        })
+    })
+}
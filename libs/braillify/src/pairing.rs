@@ -0,0 +1,282 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    error::Offset,
+    options::{EncodeOptions, Unsupported},
+    unicode::decode_unicode,
+};
+
+/// 여닫는 문장 부호의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Double,
+    Single,
+    Paren,
+    Brace,
+    Square,
+    Corner,
+    WhiteCorner,
+    Angle,
+    DoubleAngle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Open,
+    Close,
+    /// 곧은 따옴표(`"`, `'`)는 앞에 열린 따옴표가 있는지로 정합니다.
+    Either,
+}
+
+fn classify(c: char) -> Option<(Kind, Side)> {
+    Some(match c {
+        '"' => (Kind::Double, Side::Either),
+        '“' => (Kind::Double, Side::Open),
+        '”' => (Kind::Double, Side::Close),
+        '\'' => (Kind::Single, Side::Either),
+        '‘' => (Kind::Single, Side::Open),
+        '’' => (Kind::Single, Side::Close),
+        '(' => (Kind::Paren, Side::Open),
+        ')' => (Kind::Paren, Side::Close),
+        '{' => (Kind::Brace, Side::Open),
+        '}' => (Kind::Brace, Side::Close),
+        '[' => (Kind::Square, Side::Open),
+        ']' => (Kind::Square, Side::Close),
+        '「' => (Kind::Corner, Side::Open),
+        '」' => (Kind::Corner, Side::Close),
+        '『' => (Kind::WhiteCorner, Side::Open),
+        '』' => (Kind::WhiteCorner, Side::Close),
+        '〈' => (Kind::Angle, Side::Open),
+        '〉' => (Kind::Angle, Side::Close),
+        '《' => (Kind::DoubleAngle, Side::Open),
+        '》' => (Kind::DoubleAngle, Side::Close),
+        _ => return None,
+    })
+}
+
+/// 여닫는 짝이 있는 문장 부호인지 확인합니다.
+pub(crate) fn is_paired(c: char) -> bool {
+    classify(c).is_some()
+}
+
+/// 로마자 사이의 작은따옴표(don't, rock'n'roll)는 따옴표가 아니라 어퍼스트로피입니다.
+pub(crate) fn is_apostrophe(prev: Option<char>, c: char, next: Option<char>) -> bool {
+    matches!(c, '\'' | '’')
+        && prev.is_some_and(|c| c.is_ascii_alphabetic())
+        && next.is_some_and(|c| c.is_ascii_alphabetic())
+}
+
+/// 짝이 맞지 않는 문장 부호
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Diagnostic {
+    /// 닫히지 않은 여는 부호
+    Unclosed { character: char, offset: Offset },
+    /// 여는 부호 없이 나온 닫는 부호
+    Unopened { character: char, offset: Offset },
+}
+
+impl Diagnostic {
+    pub fn character(&self) -> char {
+        match self {
+            Self::Unclosed { character, .. } | Self::Unopened { character, .. } => *character,
+        }
+    }
+
+    pub fn offset(&self) -> Offset {
+        match self {
+            Self::Unclosed { offset, .. } | Self::Unopened { offset, .. } => *offset,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unclosed { character, offset } => {
+                write!(f, "Unclosed {character:?} at {offset}")
+            }
+            Self::Unopened { character, offset } => {
+                write!(f, "Unopened {character:?} at {offset}")
+            }
+        }
+    }
+}
+
+/// 열린 문장 부호를 쌓아 두고 여는 부호인지 닫는 부호인지 정합니다.
+///
+/// 작은따옴표 안의 큰따옴표처럼 겹쳐 쓴 부호는 안쪽부터 닫힙니다. 바깥 부호가 먼저 닫히면 그 안에서
+/// 열린 부호는 닫히지 않은 것으로 봅니다.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Pairing {
+    open: Vec<(Kind, char, Offset)>,
+}
+
+impl Pairing {
+    /// `c` 를 넣고 여는 부호이면 `true` 를 돌려줍니다. 짝이 맞지 않는 부호는 `mismatch` 로 알립니다.
+    pub fn push(&mut self, c: char, offset: Offset, mut mismatch: impl FnMut(Diagnostic)) -> bool {
+        let Some((kind, side)) = classify(c) else {
+            return false;
+        };
+        let position = self.open.iter().rposition(|(open, ..)| *open == kind);
+        match (side, position) {
+            (Side::Open, _) | (Side::Either, None) => {
+                self.open.push((kind, c, offset));
+                true
+            }
+            (Side::Close | Side::Either, Some(position)) => {
+                for (_, character, offset) in self.open.drain(position..).skip(1) {
+                    mismatch(Diagnostic::Unclosed { character, offset });
+                }
+                false
+            }
+            (Side::Close, None) => {
+                mismatch(Diagnostic::Unopened {
+                    character: c,
+                    offset,
+                });
+                false
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.open.is_empty()
+    }
+
+    /// 열려 있는 부호의 위치를 `f` 로 옮깁니다.
+    pub fn map_offsets(&mut self, f: impl Fn(Offset) -> Offset) {
+        for (_, _, offset) in &mut self.open {
+            *offset = f(*offset);
+        }
+    }
+
+    /// 끝까지 닫히지 않은 부호를 알립니다.
+    pub fn finish(&mut self, mut mismatch: impl FnMut(Diagnostic)) {
+        for (_, character, offset) in self.open.drain(..) {
+            mismatch(Diagnostic::Unclosed { character, offset });
+        }
+    }
+}
+
+/// 곧은 따옴표는 여는 것과 닫는 것의 점형이 다릅니다.
+/// 큰따옴표는 ⠦ ⠴, 작은따옴표는 ⠠⠦ ⠴⠄으로 적습니다.
+pub(crate) fn encode_quote(c: char, open: bool) -> Option<&'static [u8]> {
+    const DOUBLE_OPEN: &[u8] = &[decode_unicode('⠦')];
+    const DOUBLE_CLOSE: &[u8] = &[decode_unicode('⠴')];
+    const SINGLE_OPEN: &[u8] = &[decode_unicode('⠠'), decode_unicode('⠦')];
+    const SINGLE_CLOSE: &[u8] = &[decode_unicode('⠴'), decode_unicode('⠄')];
+    match (c, open) {
+        ('"', true) => Some(DOUBLE_OPEN),
+        ('"', false) => Some(DOUBLE_CLOSE),
+        ('\'', true) => Some(SINGLE_OPEN),
+        ('\'', false) => Some(SINGLE_CLOSE),
+        _ => None,
+    }
+}
+
+/// 따옴표와 괄호의 짝이 맞는지 확인합니다.
+///
+/// 점역은 짝이 맞지 않아도 계속하며, 곧은 따옴표는 앞에 열린 따옴표가 없으면 여는 따옴표로 적습니다.
+/// `Encoder::diagnostics` 와 같은 결과이며, 점역할 수 없는 문자는 건너뜁니다.
+///
+/// ```
+/// use braillify::{Diagnostic, Offset, diagnose};
+///
+/// assert!(diagnose("그는 \"'안녕'이라고 했다\"고 말했다.").is_empty());
+/// assert_eq!(
+///     diagnose("(가나"),
+///     vec![Diagnostic::Unclosed { character: '(', offset: Offset::new(0, 0) }]
+/// );
+/// ```
+pub fn diagnose(text: &str) -> Vec<Diagnostic> {
+    let options = EncodeOptions::new().unsupported(Unsupported::Skip);
+    let mut encoder = crate::encoder_for(text, &options);
+    let mut cells = Vec::new();
+    // 점역이 실패하면 실패한 곳까지의 결과를 돌려줍니다.
+    let _ = encoder
+        .encode(text, &mut cells)
+        .and_then(|()| encoder.finish(&mut cells));
+    encoder.diagnostics
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_pairing() {
+        let mut pairing = Pairing::default();
+        let mut diagnostics = Vec::new();
+        let mut push = |c| pairing.push(c, Offset::default(), |d| diagnostics.push(d));
+        assert!(push('"'));
+        assert!(push('\''));
+        assert!(!push('\''));
+        assert!(!push('"'));
+        assert!(push('“'));
+        assert!(!push('"'));
+        assert!(!push('가'));
+        assert!(diagnostics.is_empty());
+        assert!(!is_paired('가'));
+        assert!(is_apostrophe(Some('n'), '\'', Some('t')));
+        assert!(!is_apostrophe(Some('가'), '\'', Some('t')));
+        assert!(!is_apostrophe(None, '’', Some('t')));
+    }
+
+    #[test]
+    pub fn test_diagnose() {
+        assert!(diagnose("“안녕”이라고 (don't) 말했다.").is_empty());
+        assert!(diagnose("그는 '내가 \"싫어\"라고 했지'라고 말했다.").is_empty());
+        assert_eq!(
+            diagnose("가나)"),
+            vec![Diagnostic::Unopened {
+                character: ')',
+                offset: Offset::new(6, 2),
+            }]
+        );
+        // 바깥 따옴표가 먼저 닫히면 안쪽에서 열린 괄호는 닫히지 않은 것입니다.
+        assert_eq!(
+            diagnose("\"가(나\" 다"),
+            vec![Diagnostic::Unclosed {
+                character: '(',
+                offset: Offset::new(4, 2),
+            }]
+        );
+        let diagnostics = diagnose("‘가 ＂나");
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[1].character(), '"');
+        assert_eq!(diagnostics[1].offset(), Offset::new(7, 3));
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "Unclosed '‘' at char 0 (byte 0)"
+        );
+        // 문단을 넘어 닫히는 따옴표와 약어(18항) 뒤에 붙은 부호
+        assert_eq!(
+            diagnose("\"가\n\n나\" 그래서)"),
+            vec![Diagnostic::Unopened {
+                character: ')',
+                offset: Offset::new(20, 10),
+            }]
+        );
+        // 나누어 넘긴 입력도 처음부터 센 위치로 알립니다.
+        let mut encoder = crate::Encoder::new(true);
+        let mut cells = Vec::new();
+        encoder.encode("＂가", &mut cells).unwrap();
+        encoder.encode(" (나", &mut cells).unwrap();
+        encoder.finish(&mut cells).unwrap();
+        assert_eq!(
+            encoder.diagnostics(),
+            [
+                Diagnostic::Unclosed {
+                    character: '"',
+                    offset: Offset::new(0, 0),
+                },
+                Diagnostic::Unclosed {
+                    character: '(',
+                    offset: Offset::new(7, 3),
+                },
+            ]
+        );
+    }
+}
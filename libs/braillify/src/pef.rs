@@ -0,0 +1,246 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+
+#[cfg(feature = "serde")]
+use crate::layout::at_least;
+use crate::{
+    layout::{LayoutOptions, paginate},
+    span::SpannedEncoding,
+    unicode::encode_unicode,
+};
+
+/// PEF(Portable Embosser Format) 1.0 문서 설정
+///
+/// ```
+/// use braillify::{PefOptions, encode_to_pef};
+///
+/// let options = PefOptions::new("점자 교재").cells_per_row(32).rows_per_page(20);
+/// let pef = encode_to_pef("안녕하세요", &options).unwrap();
+/// assert!(pef.contains(r#"<volume cols="32" rows="20" rowgap="0" duplex="false">"#));
+/// assert!(pef.contains("<row>⠣⠒⠉⠻⠚⠠⠝⠬</row>"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct PefOptions {
+    pub(crate) title: String,
+    pub(crate) identifier: Option<String>,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "at_least::<_, 2>"))]
+    pub(crate) cells_per_row: usize,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "at_least::<_, 1>"))]
+    pub(crate) rows_per_page: usize,
+    pub(crate) pages_per_volume: Option<usize>,
+    pub(crate) duplex: bool,
+    pub(crate) page_numbers: bool,
+}
+
+impl Default for PefOptions {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            identifier: None,
+            cells_per_row: 40,
+            rows_per_page: 25,
+            pages_per_volume: None,
+            duplex: false,
+            page_numbers: false,
+        }
+    }
+}
+
+impl PefOptions {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// 문서 식별자 (`dc:identifier`), 정하지 않으면 셀 내용으로 만듭니다.
+    pub fn identifier(mut self, identifier: impl Into<String>) -> Self {
+        self.identifier = Some(identifier.into());
+        self
+    }
+
+    /// 한 줄의 칸 수 (기본값 40)
+    pub fn cells_per_row(mut self, cells: usize) -> Self {
+        self.cells_per_row = cells.max(2);
+        self
+    }
+
+    /// 한 쪽의 줄 수 (기본값 25)
+    pub fn rows_per_page(mut self, rows: usize) -> Self {
+        self.rows_per_page = rows.max(1);
+        self
+    }
+
+    /// 한 권의 쪽 수, `None` 이면 한 권에 모두 담습니다. (기본값 `None`)
+    pub fn pages_per_volume(mut self, pages: Option<usize>) -> Self {
+        self.pages_per_volume = pages.map(|pages| pages.max(1));
+        self
+    }
+
+    /// 양면 점자 여부 (기본값 false)
+    pub fn duplex(mut self, duplex: bool) -> Self {
+        self.duplex = duplex;
+        self
+    }
+
+    /// 각 쪽 첫 줄에 점자 쪽 번호를 적을지 여부 (기본값 false)
+    pub fn page_numbers(mut self, enabled: bool) -> Self {
+        self.page_numbers = enabled;
+        self
+    }
+}
+
+/// 기본 문서 식별자, 같은 셀이면 같은 값이 되도록 FNV-1a 해시를 씁니다.
+fn default_identifier(cells: &[u8]) -> String {
+    let hash = cells.iter().fold(0xcbf29ce484222325u64, |hash, cell| {
+        (hash ^ *cell as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("braillify-{hash:016x}")
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// 점역한 셀을 PEF 1.0 XML 문서로 만듭니다. 줄은 `wrap_lines` 와 같이 나눕니다.
+pub fn to_pef(encoding: &SpannedEncoding, options: &PefOptions) -> String {
+    let cells = &encoding.cells;
+    // 빈 입력도 빈 쪽 하나가 되므로 권, 절, 쪽이 적어도 하나씩 만들어집니다.
+    let pages = paginate(
+        encoding,
+        &LayoutOptions::new()
+            .line_width(options.cells_per_row)
+            .lines_per_page(options.rows_per_page)
+            .page_numbers(options.page_numbers),
+    );
+    let volumes = pages
+        .chunks(options.pages_per_volume.unwrap_or(pages.len()).max(1))
+        .collect::<Vec<_>>();
+    let identifier = options
+        .identifier
+        .clone()
+        .unwrap_or_else(|| default_identifier(cells));
+
+    let mut pef = String::new();
+    pef.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    pef.push_str("<pef version=\"2008-1\" xmlns=\"http://www.daisy.org/ns/2008/pef\">\n");
+    pef.push_str("  <head>\n");
+    pef.push_str("    <meta xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n");
+    pef.push_str("      <dc:format>application/x-pef+xml</dc:format>\n");
+    writeln!(
+        pef,
+        "      <dc:identifier>{}</dc:identifier>",
+        escape_xml(&identifier)
+    )
+    .unwrap();
+    writeln!(
+        pef,
+        "      <dc:title>{}</dc:title>",
+        escape_xml(&options.title)
+    )
+    .unwrap();
+    pef.push_str("      <dc:language>ko</dc:language>\n");
+    // 점역 프로그램을 기여자로 적습니다.
+    pef.push_str("      <dc:contributor>braillify</dc:contributor>\n");
+    pef.push_str("    </meta>\n");
+    pef.push_str("  </head>\n");
+    pef.push_str("  <body>\n");
+    for volume in volumes {
+        writeln!(
+            pef,
+            "    <volume cols=\"{}\" rows=\"{}\" rowgap=\"0\" duplex=\"{}\">",
+            options.cells_per_row, options.rows_per_page, options.duplex
+        )
+        .unwrap();
+        pef.push_str("      <section>\n");
+        for page in volume {
+            pef.push_str("        <page>\n");
+            for row in &page.lines {
+                pef.push_str("          <row>");
+                pef.extend(row.iter().map(|cell| encode_unicode(*cell)));
+                pef.push_str("</row>\n");
+            }
+            pef.push_str("        </page>\n");
+        }
+        pef.push_str("      </section>\n");
+        pef.push_str("    </volume>\n");
+    }
+    pef.push_str("  </body>\n");
+    pef.push_str("</pef>\n");
+    pef
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_to_pef() {
+        let options = PefOptions::new("<가>")
+            .identifier("id")
+            .cells_per_row(2)
+            .rows_per_page(2)
+            .pages_per_volume(Some(1))
+            .duplex(true);
+        assert_eq!(
+            to_pef(&vec![1, 2, 3, 255, 4].into(), &options),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<pef version="2008-1" xmlns="http://www.daisy.org/ns/2008/pef">
+  <head>
+    <meta xmlns:dc="http://purl.org/dc/elements/1.1/">
+      <dc:format>application/x-pef+xml</dc:format>
+      <dc:identifier>id</dc:identifier>
+      <dc:title>&lt;가&gt;</dc:title>
+      <dc:language>ko</dc:language>
+      <dc:contributor>braillify</dc:contributor>
+    </meta>
+  </head>
+  <body>
+    <volume cols="2" rows="2" rowgap="0" duplex="true">
+      <section>
+        <page>
+          <row>⠁⠤</row>
+          <row>⠂⠃</row>
+        </page>
+      </section>
+    </volume>
+    <volume cols="2" rows="2" rowgap="0" duplex="true">
+      <section>
+        <page>
+          <row>⠄</row>
+        </page>
+      </section>
+    </volume>
+  </body>
+</pef>
+"#
+        );
+    }
+
+    #[test]
+    pub fn test_default_identifier() {
+        let pef = to_pef(&Vec::new().into(), &PefOptions::default());
+        assert!(pef.contains("<dc:identifier>braillify-cbf29ce484222325</dc:identifier>"));
+        assert!(pef.contains("<page>\n          <row></row>\n        </page>"));
+        assert_ne!(default_identifier(&[1]), default_identifier(&[2]));
+        let pef = to_pef(&vec![1].into(), &PefOptions::default().page_numbers(true));
+        assert!(pef.contains(&format!("<row>⠁{}⠼⠁</row>", "⠀".repeat(37))));
+    }
+}
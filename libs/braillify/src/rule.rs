@@ -1,25 +1,430 @@
-use crate::char_struct::{CharType, KoreanChar};
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    char_struct::{CharType, KoreanChar},
+    error::BrailleError,
+    jauem::{choseong::encode_choseong, jongseong::encode_jongseong},
+    moeum::jungsong::encode_jungsong,
+    split::split_korean_jauem,
+    utils::has_choseong_o,
+};
+
+/// 약자, 약어 규정 (13~18항), `EncodeOptions::korean_contractions(false)` 가 끕니다.
+pub(crate) const CONTRACTION_RULES: [&str; 5] =
+    ["rule_13", "rule_15", "rule_16", "rule_17", "rule_18"];
+
+/// 규정이 적용되는 자리
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RulePhase {
+    /// 음절 하나를 점역합니다. 먼저 적용된 규정의 결과를 쓰며, 적용된 규정이 없으면
+    /// 1~7항 자모와 13~17항 약자로 점역합니다.
+    Syllable,
+    /// 음절을 점역한 뒤 다음 음절과의 사이에 기호를 덧붙입니다. (예: 11항 구분표)
+    After,
+}
+
+/// 점역 중인 한글 음절과 그 앞뒤 글자
+pub struct SyllableContext<'a> {
+    pub(crate) word: &'a [char],
+    pub(crate) index: usize,
+    pub(crate) korean: &'a KoreanChar,
+}
+
+impl SyllableContext<'_> {
+    /// 음절이 속한 단어
+    pub fn word(&self) -> &[char] {
+        self.word
+    }
+
+    /// 단어 안에서 음절의 위치
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn character(&self) -> char {
+        self.word[self.index]
+    }
+
+    pub fn prev(&self) -> Option<char> {
+        self.index.checked_sub(1).map(|index| self.word[index])
+    }
+
+    pub fn next(&self) -> Option<char> {
+        self.word.get(self.index + 1).copied()
+    }
+
+    /// 초성 (예: ‘까’ → ‘ㄲ’)
+    pub fn choseong(&self) -> char {
+        self.korean.cho
+    }
+
+    /// 중성
+    pub fn jungseong(&self) -> char {
+        self.korean.jung
+    }
+
+    /// 종성
+    pub fn jongseong(&self) -> Option<char> {
+        self.korean.jong
+    }
+}
+
+/// 한글 점역 규정
+///
+/// `id` 는 `rule_map.json` 의 규정 ID 이며, `EncodeOptions::rule` 로 같은 ID 의 기본 규정을
+/// 바꾸거나 `EncodeOptions::disable_rule` 로 끌 수 있습니다.
+///
+/// 이 트레이트로 구현된 기본 규정은 4항 ‘겄’, 11, 12항 구분표, 14, 16, 17항 약자의 예외입니다.
+/// 13, 15항 약자는 음절 점역(`korean_char`, `syllable`)에, 18항 약어는 단어 점역에 들어 있어
+/// 끄거나 음절 단위 규정으로 바꿀 수만 있습니다. 18항을 `rule` 로 바꾸면 약어 대신 음절마다
+/// 사용자 규정을 적용합니다.
+///
+/// ```
+/// use std::sync::Arc;
+/// use braillify::{EncodeOptions, KoreanRule, SyllableContext, encode_with_options};
+///
+/// /// ‘ㅖ’ 앞의 구분표를 ⠤ 대신 ⠀ 으로 적습니다.
+/// struct SpaceSeparator;
+///
+/// impl KoreanRule for SpaceSeparator {
+///     fn id(&self) -> &'static str {
+///         "rule_11"
+///     }
+///
+///     fn phase(&self) -> braillify::RulePhase {
+///         braillify::RulePhase::After
+///     }
+///
+///     fn encode(
+///         &self,
+///         context: &SyllableContext,
+///         result: &mut Vec<u8>,
+///     ) -> Result<bool, braillify::BrailleError> {
+///         let applies = context.jongseong().is_none() && context.next() == Some('예');
+///         if applies {
+///             result.push(0);
+///         }
+///         Ok(applies)
+///     }
+/// }
+///
+/// let options = EncodeOptions::new().rule(Arc::new(SpaceSeparator));
+/// assert_eq!(encode_with_options("아예", &options).unwrap(), vec![35, 0, 12]);
+/// ```
+pub trait KoreanRule: Send + Sync {
+    /// `rule_map.json` 의 규정 ID
+    fn id(&self) -> &'static str;
+
+    fn phase(&self) -> RulePhase {
+        RulePhase::Syllable
+    }
+
+    /// 규정을 적용할 수 있으면 `result` 에 셀을 적고 true 를 돌려줍니다.
+    fn encode(&self, context: &SyllableContext, result: &mut Vec<u8>)
+    -> Result<bool, BrailleError>;
+}
+
+/// `EncodeOptions` 에 담는 사용자 규정
+#[derive(Clone)]
+pub(crate) struct RuleOverride(pub Arc<dyn KoreanRule>);
+
+impl fmt::Debug for RuleOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RuleOverride").field(&self.0.id()).finish()
+    }
+}
+
+impl PartialEq for RuleOverride {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for RuleOverride {}
+
+/// 초성, 중성, 종성을 약자 없이 모두 적습니다.
+fn encode_full(korean: &KoreanChar, result: &mut Vec<u8>) -> Result<(), BrailleError> {
+    let (cho0, cho1) = split_korean_jauem(korean.cho)?;
+    if cho1.is_some() {
+        // 쌍자음 경우의 수
+        result.push(32);
+    }
+    result.push(encode_choseong(cho0)?);
+    result.extend(encode_jungsong(korean.jung)?);
+    if let Some(jong) = korean.jong {
+        result.extend(encode_jongseong(jong)?);
+    }
+    Ok(())
+}
+
+/// 14항 ‘나, 다, 마, 바, 자, 카, 타, 파, 하’에 모음이 붙어 나올 때에는 약자를 사용하지 않는다.
+/// 14항 [붙임] ‘팠’을 적을 때에는 ‘ㅏ’를 생략하지 않고 적는다.
+struct Rule14;
+
+impl KoreanRule for Rule14 {
+    fn id(&self) -> &'static str {
+        "rule_14"
+    }
+
+    fn encode(
+        &self,
+        context: &SyllableContext,
+        result: &mut Vec<u8>,
+    ) -> Result<bool, BrailleError> {
+        let c = context.character();
+        if c == '팠'
+            || ['나', '다', '마', '바', '자', '카', '타', '파', '하'].contains(&c)
+                && context.next().is_some_and(has_choseong_o)
+        {
+            encode_full(context.korean, result)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+/// 16항 [붙임] ‘껐’을 적을 때에는 ‘꺼’와 받침 ‘ㅆ’ 약자를 어울러 적는다.
+struct Rule16;
+
+impl KoreanRule for Rule16 {
+    fn id(&self) -> &'static str {
+        "rule_16"
+    }
+
+    fn encode(
+        &self,
+        context: &SyllableContext,
+        result: &mut Vec<u8>,
+    ) -> Result<bool, BrailleError> {
+        if context.character() == '껐' {
+            encode_full(context.korean, result)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+/// 4항 쌍받침 ‘ㅆ’은 약자인 ⠌으로 적는다.
+/// ‘겄’은 ‘것’의 약자와 받침 ‘ㅅ’으로 적지 않고 ‘거’와 받침 ‘ㅆ’으로 적는다.
+struct Rule4;
+
+impl KoreanRule for Rule4 {
+    fn id(&self) -> &'static str {
+        "rule_4"
+    }
+
+    fn encode(
+        &self,
+        context: &SyllableContext,
+        result: &mut Vec<u8>,
+    ) -> Result<bool, BrailleError> {
+        if context.character() == '겄' {
+            encode_full(context.korean, result)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+/// 17항 ‘성, 썽, 정, 쩡, 청’을 적을 때에는 ‘ㅅ, ㅆ, ㅈ, ㅉ, ㅊ’ 다음에 ‘영’의 약자 ⠻을 적어 나타낸다.
+/// 그러므로 ‘셩, 쎵, 졍, 쪙, 쳥’은 ‘영’의 약자를 쓰지 않고 초성, 중성, 종성을 모두 적는다.
+struct Rule17;
+
+impl KoreanRule for Rule17 {
+    fn id(&self) -> &'static str {
+        "rule_17"
+    }
+
+    fn encode(
+        &self,
+        context: &SyllableContext,
+        result: &mut Vec<u8>,
+    ) -> Result<bool, BrailleError> {
+        if ['셩', '쎵', '졍', '쪙', '쳥'].contains(&context.character()) {
+            encode_full(context.korean, result)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
 
 /// 5절 11항 - 모음자에 ‘예’가 붙어 나올 때에는 그 사이에 구분표 ⠤을 적어 나타낸다.
-pub fn rule_11(current: &KoreanChar, next: char, result: &mut Vec<u8>) -> Result<(), String> {
-    if let CharType::Korean(korean) = CharType::new(next)? {
-        if current.jong.is_none() && korean.cho == 'ㅇ' && korean.jung == 'ㅖ' {
+struct Rule11;
+
+impl KoreanRule for Rule11 {
+    fn id(&self) -> &'static str {
+        "rule_11"
+    }
+
+    fn phase(&self) -> RulePhase {
+        RulePhase::After
+    }
+
+    fn encode(
+        &self,
+        context: &SyllableContext,
+        result: &mut Vec<u8>,
+    ) -> Result<bool, BrailleError> {
+        if let Some(Ok(CharType::Korean(korean))) = context.next().map(CharType::new)
+            && context.korean.jong.is_none()
+            && korean.cho == 'ㅇ'
+            && korean.jung == 'ㅖ'
+        {
             result.push(36);
+            return Ok(true);
         }
+        Ok(false)
     }
-    Ok(())
 }
 
 /// 5절 12항 - ‘ㅑ, ㅘ, ㅜ, ㅝ’에 ‘애’가 붙어 나올 때에는 두 모음자 사이에 구분표 ⠤을 적어 나타낸다.
-pub fn rule_12(current: &KoreanChar, next: char, result: &mut Vec<u8>) -> Result<(), String> {
-    if let CharType::Korean(korean) = CharType::new(next)? {
-        if current.jong.is_none()
-            && ['ㅑ', 'ㅘ', 'ㅜ', 'ㅝ'].contains(&current.jung)
+struct Rule12;
+
+impl KoreanRule for Rule12 {
+    fn id(&self) -> &'static str {
+        "rule_12"
+    }
+
+    fn phase(&self) -> RulePhase {
+        RulePhase::After
+    }
+
+    fn encode(
+        &self,
+        context: &SyllableContext,
+        result: &mut Vec<u8>,
+    ) -> Result<bool, BrailleError> {
+        if let Some(Ok(CharType::Korean(korean))) = context.next().map(CharType::new)
+            && context.korean.jong.is_none()
+            && ['ㅑ', 'ㅘ', 'ㅜ', 'ㅝ'].contains(&context.korean.jung)
             && korean.cho == 'ㅇ'
             && korean.jung == 'ㅐ'
         {
             result.push(36);
+            return Ok(true);
         }
+        Ok(false)
+    }
+}
+
+/// 트레이트로 구현된 기본 규정, 같은 자리의 규정은 이 순서대로 적용합니다.
+/// 13, 15, 18항은 `RuleSet::builtin_enabled` 로 켜고 끄며 여기에는 없습니다.
+fn builtin_rules() -> Vec<Arc<dyn KoreanRule>> {
+    vec![
+        Arc::new(Rule14),
+        Arc::new(Rule16),
+        Arc::new(Rule4),
+        Arc::new(Rule17),
+        Arc::new(Rule11),
+        Arc::new(Rule12),
+    ]
+}
+
+/// 옵션을 반영한 규정 목록
+pub(crate) struct RuleSet {
+    rules: Vec<Arc<dyn KoreanRule>>,
+    /// 꺼진 규정과 사용자 규정으로 바뀐 규정
+    replaced: BTreeSet<String>,
+}
+
+impl RuleSet {
+    pub fn new(disabled: &BTreeSet<String>, overrides: &[RuleOverride]) -> Self {
+        let mut rules = builtin_rules();
+        let mut replaced = disabled.clone();
+        for RuleOverride(rule) in overrides {
+            replaced.insert(rule.id().to_string());
+            match rules.iter().position(|builtin| builtin.id() == rule.id()) {
+                Some(index) => rules[index] = rule.clone(),
+                None => rules.push(rule.clone()),
+            }
+        }
+        rules.retain(|rule| !disabled.contains(rule.id()));
+        Self { rules, replaced }
+    }
+
+    /// 기본 규정을 쓸지 확인합니다. 꺼졌거나 사용자 규정으로 바뀐 규정은 false 입니다.
+    pub fn builtin_enabled(&self, id: &str) -> bool {
+        !self.replaced.contains(id)
+    }
+
+    /// 글자 하나의 약자 규정(13, 15, 16, 17항)을 모두 쓰는지 확인합니다.
+    /// 이때는 `syllable::syllable_cells` 의 미리 계산한 표로 음절을 점역할 수 있습니다.
+    pub fn syllable_contractions(&self) -> bool {
+        ["rule_13", "rule_15", "rule_16", "rule_17"]
+            .iter()
+            .all(|id| self.builtin_enabled(id))
+    }
+
+    /// `phase` 자리의 규정을 차례로 적용하고 적용된 규정의 ID 를 `apply` 로 알립니다.
+    /// `RulePhase::Syllable` 은 처음 적용된 규정에서 멈춥니다.
+    pub fn encode(
+        &self,
+        phase: RulePhase,
+        context: &SyllableContext,
+        result: &mut Vec<u8>,
+        mut apply: impl FnMut(&'static str, &[u8]),
+    ) -> Result<bool, BrailleError> {
+        let mut applied = false;
+        for rule in self.rules.iter().filter(|rule| rule.phase() == phase) {
+            if rule.encode(context, result)? {
+                apply(rule.id(), result);
+                applied = true;
+                if phase == RulePhase::Syllable {
+                    break;
+                }
+            }
+        }
+        Ok(applied)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode_after(word: &str) -> Vec<u8> {
+        let word = word.chars().collect::<Vec<_>>();
+        let korean = KoreanChar::new(word[0]).unwrap();
+        let context = SyllableContext {
+            word: &word,
+            index: 0,
+            korean: &korean,
+        };
+        let mut result = Vec::new();
+        RuleSet::new(&BTreeSet::new(), &[])
+            .encode(RulePhase::After, &context, &mut result, |_, _| {})
+            .unwrap();
+        result
+    }
+
+    #[test]
+    pub fn test_separator_rules() {
+        assert_eq!(encode_after("아예"), vec![36]);
+        assert_eq!(encode_after("야애"), vec![36]);
+        assert_eq!(encode_after("아애"), Vec::<u8>::new());
+        assert_eq!(encode_after("악예"), Vec::<u8>::new());
+        assert_eq!(encode_after("아"), Vec::<u8>::new());
+    }
+
+    #[test]
+    pub fn test_rule_set() {
+        let disabled = BTreeSet::from(["rule_17".to_string()]);
+        let rules = RuleSet::new(&disabled, &[RuleOverride(Arc::new(Rule11))]);
+        assert!(!rules.builtin_enabled("rule_17"));
+        assert!(!rules.builtin_enabled("rule_11"));
+        assert!(rules.builtin_enabled("rule_13"));
+        assert!(!rules.syllable_contractions());
+        assert!(RuleSet::new(&BTreeSet::new(), &[]).syllable_contractions());
+        assert_eq!(
+            rules.rules.iter().map(|rule| rule.id()).collect::<Vec<_>>(),
+            vec!["rule_14", "rule_16", "rule_4", "rule_11", "rule_12"]
+        );
     }
-    Ok(())
 }
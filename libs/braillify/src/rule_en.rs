@@ -1,6 +1,6 @@
 use phf::phf_map;
 
-use crate::unicode::decode_unicode;
+use crate::{unicode::decode_unicode, utils::longest_prefix};
 static ENGLISH_SHORTCUT_MAP: phf::Map<&'static str, u8> = phf_map! {
     // 10.3
     "and" => decode_unicode('⠯'),
@@ -30,13 +30,15 @@ static ENGLISH_SHORTCUT_MAP: phf::Map<&'static str, u8> = phf_map! {
 
 /// 10.3 - 온칸 약자
 /// 10.4 - 온칸 묶음 약자
+///
+/// 가장 긴 약자를 고르므로 "the"는 "th", "ing"는 "in"보다 먼저 쓰입니다.
+/// 돌려주는 값은 점형과 약자가 더 차지하는 글자 수입니다.
 pub fn rule_en_10_4(current: &str) -> Option<(u8, usize)> {
-    for key in ENGLISH_SHORTCUT_MAP.keys() {
-        if current.starts_with(key) {
-            return Some((*ENGLISH_SHORTCUT_MAP.get(key).unwrap(), key.len() - 1));
-        }
-    }
-    None
+    longest_prefix(
+        ENGLISH_SHORTCUT_MAP.entries().map(|(k, v)| (*k, *v)),
+        current,
+    )
+    .map(|(key, code)| (code, key.len() - 1))
 }
 static ENGLISH_SHORTCUT_MAP_10_6: phf::Map<&'static str, u8> = phf_map! {
     "ea" => decode_unicode('⠂'),
@@ -51,11 +53,84 @@ static ENGLISH_SHORTCUT_MAP_10_6: phf::Map<&'static str, u8> = phf_map! {
     "in" => decode_unicode('⠔'),
 };
 /// 10.6.1 - 하위 묶음 약자 - 시작할 때 일치 해야만 함
+///
+/// 단어 첫머리에서는 10.4 약자보다 먼저 찾으며, 이 표 안에서도 가장 긴 약자를 고릅니다.
 pub fn rule_en_10_6(current: &str) -> Option<(u8, usize)> {
-    for key in ENGLISH_SHORTCUT_MAP_10_6.keys() {
-        if current.starts_with(key) {
-            return Some((*ENGLISH_SHORTCUT_MAP_10_6.get(key).unwrap(), key.len() - 1));
+    longest_prefix(
+        ENGLISH_SHORTCUT_MAP_10_6.entries().map(|(k, v)| (*k, *v)),
+        current,
+    )
+    .map(|(key, code)| (code, key.len() - 1))
+}
+
+/// 점형 하나가 여러 약자에 대응할 때에는 긴 약자를, 길이가 같으면 같은 글자가 겹치지 않는 약자("bb"보다 "be")를 고릅니다.
+fn decode_shortcut(map: &phf::Map<&'static str, u8>, code: u8) -> Option<&'static str> {
+    map.entries()
+        .filter(|(_, value)| **value == code)
+        .map(|(key, _)| *key)
+        .max_by_key(|key| (key.len(), key.as_bytes()[0] != key.as_bytes()[1]))
+}
+
+/// 10.3, 10.4 약자를 풀어 씁니다.
+pub fn decode_rule_en_10_4(code: u8) -> Option<&'static str> {
+    decode_shortcut(&ENGLISH_SHORTCUT_MAP, code)
+}
+
+/// 10.6.1 약자를 풀어 씁니다.
+pub fn decode_rule_en_10_6(code: u8) -> Option<&'static str> {
+    decode_shortcut(&ENGLISH_SHORTCUT_MAP_10_6, code)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 한 약자가 다른 약자로 시작하는 모든 쌍에서 긴 약자를 고르는지 확인합니다.
+    fn assert_longest(
+        map: &phf::Map<&'static str, u8>,
+        rule: fn(&str) -> Option<(u8, usize)>,
+    ) -> usize {
+        let mut pairs = 0;
+        for (long, code) in map.entries() {
+            for short in map
+                .keys()
+                .filter(|short| *short != long && long.starts_with(**short))
+            {
+                assert_eq!(
+                    rule(&format!("{long}s")),
+                    Some((*code, long.len() - 1)),
+                    "{short}"
+                );
+                pairs += 1;
+            }
         }
+        pairs
+    }
+
+    #[test]
+    pub fn test_rule_en_longest_match() {
+        assert_eq!(assert_longest(&ENGLISH_SHORTCUT_MAP, rule_en_10_4), 2);
+        assert_eq!(assert_longest(&ENGLISH_SHORTCUT_MAP_10_6, rule_en_10_6), 0);
+        assert_eq!(rule_en_10_4("them"), Some((decode_unicode('⠮'), 2)));
+        assert_eq!(rule_en_10_4("thin"), Some((decode_unicode('⠹'), 1)));
+        assert_eq!(rule_en_10_4("ingot"), Some((decode_unicode('⠬'), 2)));
+        assert_eq!(rule_en_10_4("ink"), Some((decode_unicode('⠔'), 1)));
+        assert_eq!(rule_en_10_4("ed"), Some((decode_unicode('⠫'), 1)));
+        assert_eq!(rule_en_10_4("er"), Some((decode_unicode('⠻'), 1)));
+        assert_eq!(rule_en_10_4("en"), Some((decode_unicode('⠢'), 1)));
+        assert_eq!(rule_en_10_4("e"), None);
+        assert_eq!(rule_en_10_6("con"), Some((decode_unicode('⠒'), 2)));
+        assert_eq!(rule_en_10_6("cc"), Some((decode_unicode('⠒'), 1)));
+        assert_eq!(rule_en_10_6("co"), None);
+    }
+
+    #[test]
+    pub fn test_decode_rule_en() {
+        assert_eq!(decode_rule_en_10_4(decode_unicode('⠯')), Some("and"));
+        assert_eq!(decode_rule_en_10_4(decode_unicode('⠬')), Some("ing"));
+        assert_eq!(decode_rule_en_10_4(decode_unicode('⠁')), None);
+        assert_eq!(decode_rule_en_10_6(decode_unicode('⠆')), Some("be"));
+        assert_eq!(decode_rule_en_10_6(decode_unicode('⠒')), Some("con"));
+        assert_eq!(decode_rule_en_10_6(decode_unicode('⠲')), Some("dis"));
     }
-    None
 }
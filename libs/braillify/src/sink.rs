@@ -0,0 +1,234 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{cell::BrailleToken, error::BrailleError};
+
+/// 점역 결과를 받는 곳
+///
+/// `encode_to_sink` 는 단어 하나를 점역할 때마다 그 결과를 차례로 `push` 합니다.
+///
+/// ```
+/// use braillify::{BrailleSink, BrailleToken, EncodeOptions, encode_to_sink};
+///
+/// /// 칸 수만 세는 출력
+/// #[derive(Default)]
+/// struct Counter(usize);
+///
+/// impl BrailleSink for Counter {
+///     fn push(&mut self, token: BrailleToken) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let mut counter = Counter::default();
+/// encode_to_sink("안녕하세요", &EncodeOptions::default(), &mut counter).unwrap();
+/// assert_eq!(counter.0, 8);
+/// ```
+pub trait BrailleSink {
+    fn push(&mut self, token: BrailleToken);
+}
+
+impl<S: BrailleSink + ?Sized> BrailleSink for &mut S {
+    fn push(&mut self, token: BrailleToken) {
+        (**self).push(token);
+    }
+}
+
+/// 점역한 셀을 `sink` 로 보내고 비웁니다. `written` 은 지금까지 보낸 셀 수입니다.
+pub(crate) fn push_cells<S: BrailleSink + ?Sized>(
+    cells: &mut Vec<u8>,
+    written: &mut usize,
+    sink: &mut S,
+) -> Result<(), BrailleError> {
+    for cell in cells.drain(..) {
+        let token = BrailleToken::from_raw(cell).map_err(|_| BrailleError::InvalidBrailleCell {
+            cell,
+            index: *written,
+        })?;
+        sink.push(token);
+        *written += 1;
+    }
+    Ok(())
+}
+
+/// `encode` 와 같은 셀 값, 빈칸은 0 이고 줄바꿈과 쪽바꿈은 255 입니다.
+impl BrailleSink for Vec<u8> {
+    fn push(&mut self, token: BrailleToken) {
+        Vec::push(self, token.to_raw());
+    }
+}
+
+/// 미리 잡아 둔 버퍼에 `encode` 와 같은 셀 값을 적습니다.
+///
+/// 버퍼가 가득 차면 더 적지 않고 칸 수만 셉니다. `len` 이 버퍼보다 크면 결과가 잘린 것입니다.
+pub(crate) struct SliceSink<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceSink<'a> {
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self { buffer, len: 0 }
+    }
+
+    /// 지금까지 받은 칸 수
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl BrailleSink for SliceSink<'_> {
+    fn push(&mut self, token: BrailleToken) {
+        if let Some(slot) = self.buffer.get_mut(self.len) {
+            *slot = token.to_raw();
+        }
+        self.len += 1;
+    }
+}
+
+impl BrailleSink for Vec<BrailleToken> {
+    fn push(&mut self, token: BrailleToken) {
+        Vec::push(self, token);
+    }
+}
+
+/// 유니코드 점자 문자열
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnicodeSink(String);
+
+impl UnicodeSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl BrailleSink for UnicodeSink {
+    fn push(&mut self, token: BrailleToken) {
+        self.0.push(token.to_unicode());
+    }
+}
+
+/// 북미 ASCII 점자 문자열
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AsciiSink(String);
+
+impl AsciiSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl BrailleSink for AsciiSink {
+    fn push(&mut self, token: BrailleToken) {
+        self.0.push(token.to_ascii());
+    }
+}
+
+/// 점 번호 표기 문자열
+///
+/// 칸은 점 번호를 이어 적고 칸 사이는 `-` 로 나눕니다. (예: ‘가나’ → `1246-14`)
+/// 빈칸은 `' '`, 줄바꿈은 `'\n'`, 쪽바꿈은 `'\u{c}'` 이며, 점이 없는 칸은 `0` 입니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DotSink {
+    text: String,
+    /// 마지막으로 적은 것이 칸인지 (다음 칸 앞에 `-` 를 적습니다)
+    after_cell: bool,
+}
+
+impl DotSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn into_string(self) -> String {
+        self.text
+    }
+}
+
+impl BrailleSink for DotSink {
+    fn push(&mut self, token: BrailleToken) {
+        match token {
+            BrailleToken::Cell(cell) => {
+                if self.after_cell {
+                    self.text.push('-');
+                }
+                if cell.is_empty() {
+                    self.text.push('0');
+                }
+                self.text
+                    .extend(cell.dots().map(|dot| char::from(b'0' + dot)));
+                self.after_cell = true;
+                return;
+            }
+            BrailleToken::Space => self.text.push(' '),
+            BrailleToken::LineBreak => self.text.push('\n'),
+            BrailleToken::PageBreak => self.text.push('\u{c}'),
+        }
+        self.after_cell = false;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cell::BrailleCell;
+
+    fn tokens() -> Vec<BrailleToken> {
+        [43, 0, 9, 255, 19, 0, 255]
+            .into_iter()
+            .map(|cell| BrailleToken::from_raw(cell).unwrap())
+            .chain([
+                BrailleToken::PageBreak,
+                BrailleToken::Cell(BrailleCell::EMPTY),
+                BrailleToken::Cell(BrailleCell::from_dots(&[1]).unwrap()),
+            ])
+            .collect()
+    }
+
+    fn collect<S: BrailleSink>(mut sink: S) -> S {
+        for token in tokens() {
+            sink.push(token);
+        }
+        sink
+    }
+
+    #[test]
+    pub fn test_sinks() {
+        assert_eq!(
+            collect(Vec::<u8>::new()),
+            vec![43, 0, 9, 255, 19, 0, 255, 255, 0, 1]
+        );
+        assert_eq!(collect(Vec::<BrailleToken>::new()), tokens());
+        let mut buffer = [0; 4];
+        assert_eq!(collect(SliceSink::new(&mut buffer)).len(), 10);
+        assert_eq!(buffer, [43, 0, 9, 255]);
+        assert_eq!(
+            collect(UnicodeSink::new()).into_string(),
+            "⠫⠀⠉\n⠓⠀\n\u{c}⠀⠁"
+        );
+        assert_eq!(collect(AsciiSink::new()).into_string(), "$ c\nh \n\u{c} a");
+        assert_eq!(
+            collect(DotSink::new()).into_string(),
+            "1246 14\n125 \n\u{c}0-1"
+        );
+    }
+}
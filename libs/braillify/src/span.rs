@@ -0,0 +1,166 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::explain::{ExplainedSpan, Rule};
+
+/// 묵자 글자 범위와 그 글자들이 만든 점자 셀 범위의 대응
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BrailleSpan {
+    /// 입력 문자열의 글자(`char`) 범위
+    ///
+    /// 로마자표 ⠴, 로마자 종료표 ⠲, 대문자 기호표 ⠠, 수표 ⠼ 처럼 특정 글자에 속하지 않는
+    /// 셀은 빈 범위(`start == end`)를 가지며, `start` 는 표시 기호가 놓인 글자 사이의 위치입니다.
+    pub source: Range<usize>,
+    /// 출력 셀 범위
+    pub cells: Range<usize>,
+}
+
+/// 점역 결과와 글자-셀 대응
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpannedEncoding {
+    pub cells: Vec<u8>,
+    /// 셀 순서대로 정렬되어 있으며 `cells` 전체를 빈틈없이 덮습니다.
+    pub spans: Vec<BrailleSpan>,
+}
+
+/// 글자 범위 없이 셀만 담습니다.
+impl From<Vec<u8>> for SpannedEncoding {
+    fn from(cells: Vec<u8>) -> Self {
+        Self {
+            cells,
+            spans: Vec::new(),
+        }
+    }
+}
+
+impl SpannedEncoding {
+    /// `char_index` 번째 글자를 점역한 셀 범위를 구합니다.
+    ///
+    /// 약어처럼 여러 글자가 함께 점역된 경우 약어 전체의 셀 범위를 돌려줍니다.
+    pub fn cells_of(&self, char_index: usize) -> Option<Range<usize>> {
+        self.spans
+            .iter()
+            .find(|span| span.source.contains(&char_index))
+            .map(|span| span.cells.clone())
+    }
+
+    /// `cell_index` 번째 셀을 만든 글자 범위를 구합니다.
+    pub fn source_of(&self, cell_index: usize) -> Option<Range<usize>> {
+        let index = self
+            .spans
+            .partition_point(|span| span.cells.end <= cell_index);
+        self.spans
+            .get(index)
+            .filter(|span| span.cells.contains(&cell_index))
+            .map(|span| span.source.clone())
+    }
+}
+
+/// 점역 중 셀이 어느 글자, 어느 규정에서 나왔는지 기록합니다.
+#[derive(Debug, Default)]
+pub(crate) struct SpanRecorder {
+    spans: Vec<BrailleSpan>,
+    rules: Vec<Option<&'static str>>,
+    /// 지금 점역 중인 문자열의 첫 글자 위치 (약어 뒤 나머지를 점역할 때 달라집니다)
+    pub text_base: usize,
+    /// 지금 점역 중인 단어의 첫 글자 위치
+    pub word_base: usize,
+    /// 이미 범위가 기록된 셀 수
+    cursor: usize,
+}
+
+impl SpanRecorder {
+    /// 기록되지 않은 셀(`cursor..cell_end`)을 단어 안의 글자 범위 `chars` 와 규정 `rule` 에 대응시킵니다.
+    pub fn record(&mut self, chars: Range<usize>, rule: Option<&'static str>, cell_end: usize) {
+        if cell_end > self.cursor {
+            self.spans.push(BrailleSpan {
+                source: self.word_base + chars.start..self.word_base + chars.end,
+                cells: self.cursor..cell_end,
+            });
+            self.rules.push(rule);
+            self.cursor = cell_end;
+        }
+    }
+
+    /// 지금까지 기록된 마지막 글자 위치
+    pub fn end(&self) -> usize {
+        self.spans.last().map_or(0, |span| span.source.end)
+    }
+
+    /// 기록한 글자 위치를 `f` 로 옮깁니다.
+    pub fn map_sources(&mut self, f: impl Fn(usize) -> usize) {
+        for span in &mut self.spans {
+            span.source = f(span.source.start)..f(span.source.end);
+        }
+    }
+
+    /// 같은 글자 범위에서 나온 셀 묶음을 하나로 합칩니다.
+    pub fn into_spans(self) -> Vec<BrailleSpan> {
+        let mut spans: Vec<BrailleSpan> = Vec::with_capacity(self.spans.len());
+        for span in self.spans {
+            match spans.last_mut() {
+                Some(last) if last.source == span.source => last.cells.end = span.cells.end,
+                _ => spans.push(span),
+            }
+        }
+        spans
+    }
+
+    pub fn into_explained(self) -> Vec<ExplainedSpan> {
+        self.spans
+            .into_iter()
+            .zip(self.rules)
+            .map(|(span, rule)| ExplainedSpan {
+                source: span.source,
+                cells: span.cells,
+                // 사용자 규정처럼 rule_map.json 에 없는 규정은 설명하지 않습니다.
+                rule: rule.and_then(Rule::get),
+            })
+            .collect()
+    }
+
+    /// 셀 묶음마다 기록된 규정 ID
+    #[cfg(test)]
+    pub fn rule_ids(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.rules.iter().flatten().copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let encoding = SpannedEncoding {
+            cells: vec![52, 1, 3, 50],
+            spans: vec![
+                BrailleSpan {
+                    source: 0..0,
+                    cells: 0..1,
+                },
+                BrailleSpan {
+                    source: 0..1,
+                    cells: 1..2,
+                },
+                BrailleSpan {
+                    source: 1..2,
+                    cells: 2..3,
+                },
+                BrailleSpan {
+                    source: 2..2,
+                    cells: 3..4,
+                },
+            ],
+        };
+        assert_eq!(encoding.cells_of(0), Some(1..2));
+        assert_eq!(encoding.cells_of(1), Some(2..3));
+        assert_eq!(encoding.cells_of(2), None);
+        assert_eq!(encoding.source_of(0), Some(0..0));
+        assert_eq!(encoding.source_of(2), Some(1..2));
+        assert_eq!(encoding.source_of(3), Some(2..2));
+        assert_eq!(encoding.source_of(4), None);
+    }
+}
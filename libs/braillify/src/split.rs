@@ -1,5 +1,9 @@
+use alloc::{vec, vec::Vec};
+
 use phf::phf_map;
 
+use crate::error::BrailleError;
+
 #[derive(Debug, PartialEq)]
 pub enum KoreanChar {
     Choseong(char),
@@ -51,23 +55,31 @@ pub static KOREAN_JAUEM_MAP: phf::Map<char, (char, Option<char>)> = phf_map! {
 };
 
 /// 자음을 분리합니다.
-pub fn split_korean_jauem(text: char) -> Result<(char, Option<char>), String> {
+pub fn split_korean_jauem(text: char) -> Result<(char, Option<char>), BrailleError> {
     if let Some((cho, jong)) = KOREAN_JAUEM_MAP.get(&text) {
         return Ok((*cho, *jong));
     }
-    Err("Invalid Korean character".to_string())
+    Err(BrailleError::invalid_jamo(text))
 }
-pub fn split_korean_char(text: char) -> Result<Vec<KoreanChar>, String> {
+/// 두 자음을 합칩니다. `split_korean_jauem`의 반대입니다.
+pub fn merge_korean_jauem(first: char, second: char) -> Option<char> {
+    KOREAN_JAUEM_MAP
+        .entries()
+        .find(|(_, value)| **value == (first, Some(second)))
+        .map(|(key, _)| *key)
+}
+
+pub fn split_korean_char(text: char) -> Result<Vec<KoreanChar>, BrailleError> {
     // check korean char
     let code = text as u32;
-    if 0x3131 <= code && code <= 0x314E {
+    if (0x3131..=0x314E).contains(&code) {
         return Ok(vec![KoreanChar::Choseong(text)]);
     }
-    if 0x314F <= code && code <= 0x3163 {
+    if (0x314F..=0x3163).contains(&code) {
         return Ok(vec![KoreanChar::Jungseong(text)]);
     }
-    if !(0xAC00 <= code && code <= 0xD7A3) {
-        return Err("Invalid Korean character".to_string());
+    if !(0xAC00..=0xD7A3).contains(&code) {
+        return Err(BrailleError::invalid_korean(text));
     }
 
     const CHOSEONG: [char; 19] = [
@@ -183,15 +195,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_merge_korean_jauem() {
+        assert_eq!(merge_korean_jauem('ㄱ', 'ㄱ'), Some('ㄲ'));
+        assert_eq!(merge_korean_jauem('ㄹ', 'ㅎ'), Some('ㅀ'));
+        assert_eq!(merge_korean_jauem('ㄱ', 'ㄴ'), None);
+    }
+
     #[test]
     fn test_split_wrong() {
         assert_eq!(
             split_korean_char('a'),
-            Err("Invalid Korean character".to_string())
+            Err(BrailleError::invalid_korean('a'))
         );
         assert_eq!(
             split_korean_char('1'),
-            Err("Invalid Korean character".to_string())
+            Err(BrailleError::invalid_korean('1'))
         );
     }
 }
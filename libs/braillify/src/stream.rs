@@ -0,0 +1,472 @@
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    io::{self, BufRead, Write},
+};
+
+use crate::{
+    Encoder, LOOKAHEAD,
+    error::{BrailleError, Offset},
+    normalize,
+    options::{Context, EncodeOptions, has_korean},
+    unicode::encode_unicode,
+};
+
+fn to_io_error(error: BrailleError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn from_utf8(bytes: Vec<u8>) -> io::Result<String> {
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// 점역을 기다리는 단어
+struct Word {
+    /// 정규화한 단어
+    text: String,
+    /// 정규화로 바뀐 단어의 원문
+    original: Option<String>,
+    /// 원문 기준 시작 위치
+    offset: Offset,
+}
+
+/// 나뉘어 들어오는 입력을 띄어쓰기 단위로 모아 점역합니다.
+///
+/// 단어는 뒤의 `LOOKAHEAD` 단어가 들어온 뒤에 점역하므로 한 번에 점역한 결과와 같습니다.
+struct StreamEncoder {
+    encoder: Encoder,
+    /// 아직 띄어쓰기를 만나지 못한 마지막 단어
+    pending: Vec<u8>,
+    /// `pending` 의 원문 기준 시작 위치
+    offset: Offset,
+    words: VecDeque<Word>,
+    prev_word: String,
+    /// 로마자표를 적을지 정했는지, `Context::Auto` 는 한글이 처음 나오거나 입력이 끝날 때 정합니다.
+    decided: bool,
+}
+
+impl StreamEncoder {
+    fn new(options: &EncodeOptions) -> Self {
+        let mut encoder = Encoder::with_options(options.clone());
+        let decided = options.context != Context::Auto;
+        if !decided {
+            encoder.english_indicator = false;
+        }
+        Self {
+            encoder,
+            pending: Vec::new(),
+            offset: Offset::default(),
+            words: VecDeque::new(),
+            prev_word: String::new(),
+            decided,
+        }
+    }
+
+    fn push(&mut self, bytes: &[u8], result: &mut Vec<u8>) -> io::Result<()> {
+        // 이미 받아 둔 바이트에는 ' ' 가 없으므로 새로 받은 바이트만 찾습니다.
+        // ' ' 는 UTF-8 다중 바이트 문자 안에 나오지 않으므로 그 앞은 온전한 문자열입니다.
+        let Some(end) = bytes.iter().rposition(|byte| *byte == b' ') else {
+            self.pending.extend_from_slice(bytes);
+            return Ok(());
+        };
+        let end = self.pending.len() + end;
+        self.pending.extend_from_slice(bytes);
+        let rest = self.pending.split_off(end + 1);
+        let text = from_utf8(std::mem::replace(&mut self.pending, rest))?;
+        self.push_words(&text);
+        self.drain(result, false)
+    }
+
+    fn finish(&mut self, result: &mut Vec<u8>) -> io::Result<()> {
+        let text = from_utf8(std::mem::take(&mut self.pending))?;
+        self.push_words(&text);
+        self.drain(result, true)?;
+        self.encoder.finish(result).map_err(to_io_error)
+    }
+
+    fn push_words(&mut self, text: &str) {
+        let normalize = self.encoder.options.normalize;
+        // `encode` 와 같이 정규화한 뒤 띄어 쓴 곳에서 나누므로 전각 빈칸(U+3000)에서도 나눕니다.
+        let is_space = |c| c == ' ' || normalize && c == '\u{3000}';
+        for chunk in text.split_inclusive(is_space) {
+            let word = chunk.strip_suffix(is_space).unwrap_or(chunk);
+            let normalized = if normalize {
+                normalize::normalize(word)
+            } else {
+                Cow::Borrowed(word)
+            };
+            if self.encoder.keeps_word(&normalized) {
+                if !self.decided && has_korean(word) {
+                    self.decided = true;
+                    self.encoder.english_indicator = true;
+                }
+                let (text, original) = match normalized {
+                    Cow::Borrowed(word) => (word.to_string(), None),
+                    Cow::Owned(normalized) => (normalized, Some(word.to_string())),
+                };
+                self.words.push_back(Word {
+                    text,
+                    original,
+                    offset: self.offset,
+                });
+            }
+            self.offset = self.offset + Offset::new(chunk.len(), chunk.chars().count());
+        }
+    }
+
+    fn drain(&mut self, result: &mut Vec<u8>, at_end: bool) -> io::Result<()> {
+        if !self.decided {
+            if !at_end {
+                return Ok(());
+            }
+            // 끝까지 한글이 없으면 영어 문장입니다.
+            self.decided = true;
+        }
+        while self.words.len() > LOOKAHEAD || (at_end && !self.words.is_empty()) {
+            let word = self.words.pop_front().unwrap();
+            let remaining_words = self
+                .words
+                .iter()
+                .map(|word| word.text.as_str())
+                .collect::<Vec<_>>();
+            let mut skip_count = 0;
+            let mut position = 0;
+            self.encoder
+                .encode_word(
+                    &word.text,
+                    &self.prev_word,
+                    &remaining_words,
+                    &mut skip_count,
+                    &mut position,
+                    result,
+                )
+                .map_err(|e| {
+                    // 오류 위치는 정규화하기 전의 원문 기준으로 돌려줍니다.
+                    let e = e.shift(Offset::of_char(&word.text, position));
+                    let e = match &word.original {
+                        Some(original) => {
+                            e.map_offset(|offset| normalize::original_offset(original, offset))
+                        }
+                        None => e,
+                    };
+                    to_io_error(e.shift(word.offset))
+                })?;
+            self.prev_word = word.text;
+        }
+        Ok(())
+    }
+}
+
+/// 쓰는 글을 점역하여 유니코드 점자로 `inner` 에 씁니다.
+///
+/// 입력은 띄어쓰기 단위로 모아 점역하므로 어떻게 나누어 쓰더라도 `encode_to_unicode` 와
+/// 같은 결과가 됩니다. 마지막 단어는 `finish` 를 불러야 점역됩니다.
+///
+/// `Context::Auto` 는 한글이 처음 나올 때까지, 한글이 없으면 `finish` 까지 입력을 모아 두었다가
+/// 점역합니다. 한글이 없는 긴 글을 바로바로 점역하려면 `Context` 를 지정하세요.
+///
+/// ```
+/// use std::io::Write;
+/// use braillify::BrailleWriter;
+///
+/// let mut writer = BrailleWriter::new(Vec::new());
+/// writer.write_all("안녕 반".as_bytes()).unwrap();
+/// writer.write_all("가워".as_bytes()).unwrap();
+/// let output = writer.finish().unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "⠣⠒⠉⠻⠀⠘⠒⠫⠏");
+/// ```
+///
+/// 점역 오류는 `io::ErrorKind::InvalidData` 이며 `BrailleError` 를 담습니다.
+pub struct BrailleWriter<W: Write> {
+    inner: W,
+    stream: StreamEncoder,
+    cells: Vec<u8>,
+}
+
+impl<W: Write> BrailleWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self::with_options(inner, &EncodeOptions::default())
+    }
+
+    pub fn with_options(inner: W, options: &EncodeOptions) -> Self {
+        Self {
+            inner,
+            stream: StreamEncoder::new(options),
+            cells: Vec::new(),
+        }
+    }
+
+    /// 남은 단어를 점역하여 쓰고 `inner` 를 돌려줍니다.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.stream.finish(&mut self.cells)?;
+        self.write_cells()?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_cells(&mut self) -> io::Result<()> {
+        if !self.cells.is_empty() {
+            let text = self.cells.drain(..).map(encode_unicode).collect::<String>();
+            self.inner.write_all(text.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for BrailleWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.push(buf, &mut self.cells)?;
+        self.write_cells()?;
+        Ok(buf.len())
+    }
+
+    /// 점역된 셀을 `inner` 로 보냅니다. 띄어쓰기를 만나지 못한 단어는 남아 있습니다.
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_cells()?;
+        self.inner.flush()
+    }
+}
+
+/// `BufRead` 에서 읽은 글을 점역한 셀을 차례로 돌려줍니다.
+///
+/// 읽은 내용마다 점역이 끝난 셀을 돌려주며, 모두 이으면 `encode` 의 결과와 같습니다.
+///
+/// ```
+/// use braillify::{EncodeReader, encode};
+///
+/// let text = "상상이상의 세계";
+/// let reader = std::io::BufReader::with_capacity(4, text.as_bytes());
+/// let cells = EncodeReader::new(reader)
+///     .collect::<std::io::Result<Vec<_>>>()
+///     .unwrap()
+///     .concat();
+/// assert_eq!(cells, encode(text).unwrap());
+/// ```
+pub struct EncodeReader<R: BufRead> {
+    reader: R,
+    stream: StreamEncoder,
+    done: bool,
+}
+
+impl<R: BufRead> EncodeReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, &EncodeOptions::default())
+    }
+
+    pub fn with_options(reader: R, options: &EncodeOptions) -> Self {
+        Self {
+            reader,
+            stream: StreamEncoder::new(options),
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for EncodeReader<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let mut cells = Vec::new();
+            let result = match self.reader.fill_buf() {
+                Ok([]) => {
+                    self.done = true;
+                    self.stream.finish(&mut cells)
+                }
+                Ok(buf) => {
+                    let len = buf.len();
+                    let result = self.stream.push(buf, &mut cells);
+                    self.reader.consume(len);
+                    result
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) if cells.is_empty() => {}
+                Ok(()) => return Some(Ok(cells)),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::BufReader;
+
+    use super::*;
+    use crate::{encode_to_unicode_with_options, encode_with_options, test::test_cases};
+
+    fn write_chunked(text: &str, chunk_size: usize, options: &EncodeOptions) -> io::Result<String> {
+        let mut writer = BrailleWriter::with_options(Vec::new(), options);
+        for chunk in text.as_bytes().chunks(chunk_size) {
+            writer.write_all(chunk)?;
+        }
+        Ok(String::from_utf8(writer.finish()?).unwrap())
+    }
+
+    fn read_chunked(text: &str, capacity: usize, options: &EncodeOptions) -> io::Result<Vec<u8>> {
+        let reader = BufReader::with_capacity(capacity, text.as_bytes());
+        Ok(EncodeReader::with_options(reader, options)
+            .collect::<io::Result<Vec<_>>>()?
+            .concat())
+    }
+
+    #[test]
+    pub fn test_stream_by_testcase() {
+        let options = EncodeOptions::default();
+        for (input, expected) in test_cases() {
+            for size in [1, 2, 5, 64] {
+                assert_eq!(
+                    write_chunked(&input, size, &options).unwrap(),
+                    expected
+                        .iter()
+                        .copied()
+                        .map(encode_unicode)
+                        .collect::<String>(),
+                    "{input:?} in chunks of {size}"
+                );
+                assert_eq!(
+                    read_chunked(&input, size, &options).unwrap(),
+                    expected,
+                    "{input:?} in chunks of {size}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_stream_context() {
+        for text in [
+            "Table of Contents",
+            "ABC DEF GHI JKL",
+            "THE QUICK BROWN 여우",
+            "  Roma  [ㄹㄹ로마] ",
+            "안녕\n반가워 Hello World",
+        ] {
+            for options in [
+                EncodeOptions::default(),
+                EncodeOptions::default().context(Context::English),
+                EncodeOptions::default().context(Context::Korean),
+            ] {
+                let expected = encode_to_unicode_with_options(text, &options).unwrap();
+                for size in [1, 3, 7] {
+                    assert_eq!(write_chunked(text, size, &options).unwrap(), expected);
+                    assert_eq!(
+                        read_chunked(text, size, &options).unwrap(),
+                        encode_with_options(text, &options).unwrap()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_stream_auto_context() {
+        // 한글이 나오기 전까지는 점역하지 않고 모아 둡니다.
+        let mut stream = StreamEncoder::new(&EncodeOptions::default());
+        let mut cells = Vec::new();
+        stream
+            .push("word ".repeat(300).as_bytes(), &mut cells)
+            .unwrap();
+        assert!(cells.is_empty());
+        stream.push("한글 ".as_bytes(), &mut cells).unwrap();
+        assert!(!cells.is_empty());
+
+        // 한글이 몇 번째 단어에서 처음 나오더라도 한 번에 점역한 결과와 같습니다.
+        for text in [
+            "word ".repeat(300) + "한글",
+            "word ".repeat(1000),
+            "A ".repeat(500) + "가 B",
+        ] {
+            let expected = crate::encode(&text).unwrap();
+            for size in [1, 16, 1024] {
+                assert_eq!(
+                    read_chunked(&text, size, &EncodeOptions::default()).unwrap(),
+                    expected
+                );
+            }
+            assert_eq!(
+                write_chunked(&text, 7, &EncodeOptions::default()).unwrap(),
+                crate::encode_to_unicode(&text).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_stream_normalize() {
+        for text in [
+            "\u{1100}\u{1161}\u{11AB}\u{1102}\u{1161}\u{11BC} 반가워",
+            "ＡＢＣ　１２３ 가나",
+            "가\u{3000}나 다\u{3000}\u{3000}라",
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF} ＴＶ를",
+        ] {
+            let options = EncodeOptions::default();
+            let expected = crate::encode(text).unwrap();
+            for size in [1, 2, 5, 64] {
+                assert_eq!(
+                    read_chunked(text, size, &options).unwrap(),
+                    expected,
+                    "{text:?} in chunks of {size}"
+                );
+                assert_eq!(
+                    write_chunked(text, size, &options).unwrap(),
+                    crate::encode_to_unicode(text).unwrap()
+                );
+            }
+            let options = options.normalize(false);
+            assert_eq!(
+                read_chunked(text, 3, &options).ok(),
+                encode_with_options(text, &options).ok()
+            );
+        }
+
+        // 오류 위치는 정규화하기 전의 원문 기준입니다.
+        let text = "ＡＢ　\u{1100}\u{1161}\u{11AB}😀";
+        let error = read_chunked(text, 4, &EncodeOptions::default()).unwrap_err();
+        assert_eq!(
+            error
+                .into_inner()
+                .and_then(|e| e.downcast::<BrailleError>().ok())
+                .map(|e| *e),
+            crate::encode(text).err()
+        );
+    }
+
+    #[test]
+    pub fn test_stream_error() {
+        let error = write_chunked("안녕 😀", 1, &EncodeOptions::default()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error
+                .get_ref()
+                .and_then(|e| e.downcast_ref::<BrailleError>()),
+            Some(&BrailleError::UnsupportedCharacter {
+                character: '😀',
+                offset: Offset::new(7, 3),
+            })
+        );
+
+        let error = read_chunked("가 나 다\u{0}", 2, &EncodeOptions::default()).unwrap_err();
+        assert_eq!(
+            error
+                .into_inner()
+                .and_then(|e| e.downcast::<BrailleError>().ok())
+                .and_then(|e| e.offset()),
+            Some(Offset::new(11, 5))
+        );
+
+        // 끝나지 않은 UTF-8 문자
+        let mut writer = BrailleWriter::new(Vec::new());
+        writer.write_all(&"가".as_bytes()[..2]).unwrap();
+        assert_eq!(
+            writer.finish().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use crate::unicode::decode_unicode;
+
+/// 한 음절을 적는 데 필요한 최대 칸 수 (예: ‘쬁’ = 된소리표, ㅈ, ㅙ(2칸), ㄾ(2칸))
+const MAX_CELLS: usize = 6;
+const SYLLABLE_COUNT: usize = 11172;
+
+/// 초성이 없음을 나타내는 값, 첫소리 ‘ㅇ’ 은 적지 않습니다.
+const NONE: u8 = u8::MAX;
+
+/// 초성 19자의 (된소리표를 뺀 초성 순번, 된소리 여부)
+/// ㄱ ㄲ ㄴ ㄷ ㄸ ㄹ ㅁ ㅂ ㅃ ㅅ ㅆ ㅇ ㅈ ㅉ ㅊ ㅋ ㅌ ㅍ ㅎ
+const CHOSEONG_SPLIT: [(usize, bool); 19] = [
+    (0, false),
+    (0, true),
+    (2, false),
+    (3, false),
+    (3, true),
+    (5, false),
+    (6, false),
+    (7, false),
+    (7, true),
+    (9, false),
+    (9, true),
+    (11, false),
+    (12, false),
+    (12, true),
+    (14, false),
+    (15, false),
+    (16, false),
+    (17, false),
+    (18, false),
+];
+
+/// 1항 초성 (된소리는 쓰지 않습니다)
+const CHOSEONG: [u8; 19] = [
+    decode_unicode('⠈'),
+    NONE,
+    decode_unicode('⠉'),
+    decode_unicode('⠊'),
+    NONE,
+    decode_unicode('⠐'),
+    decode_unicode('⠑'),
+    decode_unicode('⠘'),
+    NONE,
+    decode_unicode('⠠'),
+    NONE,
+    NONE,
+    decode_unicode('⠨'),
+    NONE,
+    decode_unicode('⠰'),
+    decode_unicode('⠋'),
+    decode_unicode('⠓'),
+    decode_unicode('⠙'),
+    decode_unicode('⠚'),
+];
+
+/// 6, 7항 중성, 한 칸짜리는 두 번째 칸이 0 입니다.
+/// ㅏ ㅐ ㅑ ㅒ ㅓ ㅔ ㅕ ㅖ ㅗ ㅘ ㅙ ㅚ ㅛ ㅜ ㅝ ㅞ ㅟ ㅠ ㅡ ㅢ ㅣ
+const JUNGSEONG: [[u8; 2]; 21] = [
+    [decode_unicode('⠣'), 0],
+    [decode_unicode('⠗'), 0],
+    [decode_unicode('⠜'), 0],
+    [decode_unicode('⠜'), decode_unicode('⠗')],
+    [decode_unicode('⠎'), 0],
+    [decode_unicode('⠝'), 0],
+    [decode_unicode('⠱'), 0],
+    [decode_unicode('⠌'), 0],
+    [decode_unicode('⠥'), 0],
+    [decode_unicode('⠧'), 0],
+    [decode_unicode('⠧'), decode_unicode('⠗')],
+    [decode_unicode('⠽'), 0],
+    [decode_unicode('⠬'), 0],
+    [decode_unicode('⠍'), 0],
+    [decode_unicode('⠏'), 0],
+    [decode_unicode('⠏'), decode_unicode('⠗')],
+    [decode_unicode('⠍'), decode_unicode('⠗')],
+    [decode_unicode('⠩'), 0],
+    [decode_unicode('⠪'), 0],
+    [decode_unicode('⠺'), 0],
+    [decode_unicode('⠕'), 0],
+];
+
+/// 3, 4, 5항 종성 (첫 번째는 받침 없음)
+/// ㄱ ㄲ ㄳ ㄴ ㄵ ㄶ ㄷ ㄹ ㄺ ㄻ ㄼ ㄽ ㄾ ㄿ ㅀ ㅁ ㅂ ㅄ ㅅ ㅆ ㅇ ㅈ ㅊ ㅋ ㅌ ㅍ ㅎ
+const JONGSEONG: [[u8; 2]; 28] = [
+    [0, 0],
+    [decode_unicode('⠁'), 0],
+    [decode_unicode('⠁'), decode_unicode('⠁')],
+    [decode_unicode('⠁'), decode_unicode('⠄')],
+    [decode_unicode('⠒'), 0],
+    [decode_unicode('⠒'), decode_unicode('⠅')],
+    [decode_unicode('⠒'), decode_unicode('⠴')],
+    [decode_unicode('⠔'), 0],
+    [decode_unicode('⠂'), 0],
+    [decode_unicode('⠂'), decode_unicode('⠁')],
+    [decode_unicode('⠂'), decode_unicode('⠢')],
+    [decode_unicode('⠂'), decode_unicode('⠃')],
+    [decode_unicode('⠂'), decode_unicode('⠄')],
+    [decode_unicode('⠂'), decode_unicode('⠦')],
+    [decode_unicode('⠂'), decode_unicode('⠲')],
+    [decode_unicode('⠂'), decode_unicode('⠴')],
+    [decode_unicode('⠢'), 0],
+    [decode_unicode('⠃'), 0],
+    [decode_unicode('⠃'), decode_unicode('⠄')],
+    [decode_unicode('⠄'), 0],
+    [decode_unicode('⠌'), 0],
+    [decode_unicode('⠶'), 0],
+    [decode_unicode('⠅'), 0],
+    [decode_unicode('⠆'), 0],
+    [decode_unicode('⠖'), 0],
+    [decode_unicode('⠦'), 0],
+    [decode_unicode('⠲'), 0],
+    [decode_unicode('⠴'), 0],
+];
+
+/// 겹받침과 쌍받침을 (첫 받침 순번, 나머지 받침 순번)으로 나눕니다. 나머지가 없으면 0 입니다.
+const JONGSEONG_SPLIT: [(usize, usize); 28] = [
+    (0, 0),
+    (1, 0),
+    (1, 1),
+    (1, 19),
+    (4, 0),
+    (4, 22),
+    (4, 27),
+    (7, 0),
+    (8, 0),
+    (8, 1),
+    (8, 16),
+    (8, 17),
+    (8, 19),
+    (8, 25),
+    (8, 26),
+    (8, 27),
+    (16, 0),
+    (17, 0),
+    (17, 19),
+    (19, 0),
+    (19, 19),
+    (21, 0),
+    (22, 0),
+    (23, 0),
+    (24, 0),
+    (25, 0),
+    (26, 0),
+    (27, 0),
+];
+
+/// 13, 15, 17항 약자를 (초성, 중성, 종성) 순번으로 찾습니다.
+/// 한 칸짜리는 두 번째 칸이 0 입니다.
+const fn shortcut(cho: usize, jung: usize, jong: usize) -> Option<[u8; 2]> {
+    Some(match (cho, jung, jong) {
+        // 13항 가 나 다 마 바 사 자 카 타 파 하
+        (0, 0, 0) => [decode_unicode('⠫'), 0],
+        (2, 0, 0) => [decode_unicode('⠉'), 0],
+        (3, 0, 0) => [decode_unicode('⠊'), 0],
+        (6, 0, 0) => [decode_unicode('⠑'), 0],
+        (7, 0, 0) => [decode_unicode('⠘'), 0],
+        (9, 0, 0) => [decode_unicode('⠇'), 0],
+        (12, 0, 0) => [decode_unicode('⠨'), 0],
+        (15, 0, 0) => [decode_unicode('⠋'), 0],
+        (16, 0, 0) => [decode_unicode('⠓'), 0],
+        (17, 0, 0) => [decode_unicode('⠙'), 0],
+        (18, 0, 0) => [decode_unicode('⠚'), 0],
+        // 15항 것
+        (0, 4, 19) => [decode_unicode('⠸'), decode_unicode('⠎')],
+        // 15항 억 언 얼 연 열 영 옥 온 옹 운 울 은 을 인
+        (11, 4, 1) => [decode_unicode('⠹'), 0],
+        (11, 4, 4) => [decode_unicode('⠾'), 0],
+        (11, 4, 8) => [decode_unicode('⠞'), 0],
+        (11, 6, 4) => [decode_unicode('⠡'), 0],
+        (11, 6, 8) => [decode_unicode('⠳'), 0],
+        (11, 6, 21) => [decode_unicode('⠻'), 0],
+        (11, 8, 1) => [decode_unicode('⠭'), 0],
+        (11, 8, 4) => [decode_unicode('⠷'), 0],
+        (11, 8, 21) => [decode_unicode('⠿'), 0],
+        (11, 13, 4) => [decode_unicode('⠛'), 0],
+        (11, 13, 8) => [decode_unicode('⠯'), 0],
+        (11, 18, 4) => [decode_unicode('⠵'), 0],
+        (11, 18, 8) => [decode_unicode('⠮'), 0],
+        (11, 20, 4) => [decode_unicode('⠟'), 0],
+        // 17항 성 정 청
+        (9, 4, 21) => [decode_unicode('⠠'), decode_unicode('⠻')],
+        (12, 4, 21) => [decode_unicode('⠨'), decode_unicode('⠻')],
+        (14, 4, 21) => [decode_unicode('⠰'), decode_unicode('⠻')],
+        _ => return None,
+    })
+}
+
+/// 음절 하나를 적은 칸
+///
+/// 표가 11172개이므로 `len` 을 `u8` 로 두어 한 음절을 7바이트에 담습니다.
+#[derive(Clone, Copy)]
+struct Cells {
+    cells: [u8; MAX_CELLS],
+    len: u8,
+}
+
+impl Cells {
+    const fn push(mut self, cell: u8) -> Self {
+        if cell != 0 && cell != NONE {
+            self.cells[self.len as usize] = cell;
+            self.len += 1;
+        }
+        self
+    }
+
+    const fn extend(mut self, cells: &[u8]) -> Self {
+        let mut i = 0;
+        while i < cells.len() {
+            self = self.push(cells[i]);
+            i += 1;
+        }
+        self
+    }
+}
+
+/// 약자를 모두 쓸 때 `index` 번째 음절(U+AC00 부터)을 적는 칸
+///
+/// `korean_char::encode_korean_char` 와 같은 순서로 약자를 찾습니다.
+/// ‘ㅇ’ 으로 시작하는 받침 약자(억, 옥 등), 초성을 포함한 받침 약자(것, 성 등),
+/// 받침 없는 약자(가, 나 등) 순서이며, 약자가 없으면 초성, 중성, 종성을 모두 적습니다.
+const fn encode_syllable(index: usize) -> Cells {
+    let (cho, double) = CHOSEONG_SPLIT[index / 588];
+    let jung = index % 588 / 28;
+    let jong = index % 28;
+    let (jong0, jong1) = JONGSEONG_SPLIT[jong];
+
+    // 된소리는 된소리표 ⠠ 를 앞에 적습니다.
+    let cells = Cells {
+        cells: [0; MAX_CELLS],
+        len: 0,
+    };
+    let cells = if double {
+        cells.push(decode_unicode('⠠'))
+    } else {
+        cells
+    };
+    if jong != 0 {
+        if let Some(code) = shortcut(11, jung, jong0) {
+            return cells
+                .push(CHOSEONG[cho])
+                .extend(&code)
+                .extend(&JONGSEONG[jong1]);
+        }
+        if let Some(code) = shortcut(cho, jung, jong0) {
+            return cells.extend(&code).extend(&JONGSEONG[jong1]);
+        }
+    }
+    if let Some(code) = shortcut(cho, jung, 0) {
+        return cells.extend(&code).extend(&JONGSEONG[jong]);
+    }
+    cells
+        .push(CHOSEONG[cho])
+        .extend(&JUNGSEONG[jung])
+        .extend(&JONGSEONG[jong])
+}
+
+/// U+AC00..=U+D7A3 의 모든 음절을 약자를 써서 적은 칸
+///
+/// 앞뒤 글자를 보는 규정(11, 12항, 14항 [다만], 16항 [붙임], 17항 [붙임])은 이 표에 없으며,
+/// 점역할 때 `rule::KoreanRule` 로 이 표보다 먼저 적용합니다.
+static SYLLABLE_TABLE: [Cells; SYLLABLE_COUNT] = {
+    let mut table = [Cells {
+        cells: [0; MAX_CELLS],
+        len: 0,
+    }; SYLLABLE_COUNT];
+    let mut index = 0;
+    while index < SYLLABLE_COUNT {
+        table[index] = encode_syllable(index);
+        index += 1;
+    }
+    table
+};
+
+/// 약자를 모두 쓸 때 한글 음절을 적는 칸, 한글 음절이 아니면 `None` 입니다.
+pub fn syllable_cells(c: char) -> Option<&'static [u8]> {
+    let index = (c as usize).checked_sub(0xAC00)?;
+    let cells = SYLLABLE_TABLE.get(index)?;
+    Some(&cells.cells[..cells.len as usize])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{char_struct::KoreanChar, korean_char::encode_korean_char};
+
+    #[test]
+    pub fn test_syllable_table() {
+        for index in 0..SYLLABLE_COUNT {
+            let c = char::from_u32(0xAC00 + index as u32).unwrap();
+            let (expected, _) = encode_korean_char(&KoreanChar::new(c).unwrap(), |_| true).unwrap();
+            assert_eq!(syllable_cells(c), Some(&expected[..]), "{c}");
+        }
+        assert_eq!(syllable_cells('가'), Some(&[decode_unicode('⠫')][..]));
+        assert_eq!(syllable_cells('\u{ABFF}'), None);
+        assert_eq!(syllable_cells('\u{D7A4}'), None);
+        assert_eq!(syllable_cells('a'), None);
+        assert_eq!(core::mem::size_of::<Cells>(), MAX_CELLS + 1);
+    }
+}
@@ -1,12 +1,13 @@
 use phf::phf_map;
 
-use crate::unicode::decode_unicode;
+use crate::{error::BrailleError, unicode::decode_unicode, utils::longest_match};
 
 static SHORTCUT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    // 곧은 따옴표의 닫는 점형은 `pairing::encode_quote` 에서 고릅니다.
     '"' => &[decode_unicode('⠦')],
-    // '"' => &[decode_unicode('⠴')],
     '\'' => &[decode_unicode('⠠'), decode_unicode('⠦')],
-    // '\'' => &[decode_unicode('⠴'), decode_unicode('⠄')],
+    '“' => &[decode_unicode('⠦')],
+    '”' => &[decode_unicode('⠴')],
     '~' => &[decode_unicode('⠈'), decode_unicode('⠔')],
     '…' => &[decode_unicode('⠲'), decode_unicode('⠲'), decode_unicode('⠲')],
     '⋯' => &[decode_unicode('⠠'), decode_unicode('⠠'), decode_unicode('⠠')],
@@ -39,14 +40,27 @@ static SHORTCUT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     '-' => &[decode_unicode('⠤')],
 };
 
-pub fn encode_char_symbol_shortcut(text: char) -> Result<&'static [u8], String> {
+pub fn encode_char_symbol_shortcut(text: char) -> Result<&'static [u8], BrailleError> {
     if let Some(code) = SHORTCUT_MAP.get(&text) {
         Ok(code)
     } else {
-        Err("Invalid symbol character".to_string())
+        Err(BrailleError::invalid_symbol(text))
     }
 }
 
+/// 같은 점형을 쓰는 기호 중 역점역할 때 고르지 않는 기호
+const DECODE_SKIP: [char; 5] = ['"', '\'', '“', '”', '_'];
+
+pub fn decode_char_symbol_shortcut(code: &[u8]) -> Option<(char, usize)> {
+    longest_match(
+        SHORTCUT_MAP
+            .entries()
+            .filter(|(k, _)| !DECODE_SKIP.contains(k))
+            .map(|(k, v)| (*k, *v)),
+        code,
+    )
+}
+
 pub fn is_symbol_char(text: char) -> bool {
     SHORTCUT_MAP.contains_key(&text)
 }
@@ -138,4 +152,33 @@ mod test {
             &[decode_unicode('⠠'), decode_unicode('⠴')]
         );
     }
+
+    #[test]
+    pub fn test_decode_char_symbol_shortcut() {
+        assert_eq!(
+            decode_char_symbol_shortcut(&[decode_unicode('⠦')]),
+            Some(('?', 1))
+        );
+        assert_eq!(
+            decode_char_symbol_shortcut(&[decode_unicode('⠦'), decode_unicode('⠄')]),
+            Some(('(', 2))
+        );
+        assert_eq!(
+            decode_char_symbol_shortcut(&[decode_unicode('⠠'), decode_unicode('⠦')]),
+            Some(('‘', 2))
+        );
+        assert_eq!(
+            decode_char_symbol_shortcut(&[decode_unicode('⠤')]),
+            Some(('-', 1))
+        );
+        assert_eq!(
+            decode_char_symbol_shortcut(&[
+                decode_unicode('⠲'),
+                decode_unicode('⠲'),
+                decode_unicode('⠲')
+            ]),
+            Some(('…', 3))
+        );
+        assert_eq!(decode_char_symbol_shortcut(&[decode_unicode('⠁')]), None);
+    }
 }
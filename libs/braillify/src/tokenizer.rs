@@ -0,0 +1,132 @@
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+use crate::{
+    char_struct::CharType,
+    old_hangul::{is_old_hangul_char, is_old_hangul_part},
+};
+
+/// 한글 음절과 옛한글(첫가끝 자모, 옛 호환 자모)
+fn is_korean_char(c: char) -> bool {
+    ('\u{AC00}'..='\u{D7A3}').contains(&c) || is_old_hangul_char(c) || is_old_hangul_part(c)
+}
+
+/// 단어 하나를 한 번 훑어 글자마다 종류를 정한 결과
+///
+/// 점역 규칙이 앞뒤 글자를 살필 때 글자를 다시 분류하거나 단어를 다시 훑지 않도록,
+/// 필요한 정보를 모두 이 단계에서 모아 둡니다. 만드는 데에도, 조회하는 데에도 단어 길이에 비례하는
+/// 시간만 들기 때문에 긴 단어(URL, 염기 서열, base64 등)도 선형 시간에 점역됩니다.
+pub(crate) struct TokenizedWord {
+    pub chars: Vec<char>,
+    /// 글자마다의 종류, 점역할 수 없는 글자는 `None`
+    pub types: Vec<Option<CharType>>,
+    /// 점역할 수 없는 첫 글자의 위치
+    pub first_unsupported: Option<usize>,
+    pub is_all_uppercase: bool,
+    pub has_korean_char: bool,
+    /// 로마자 약자를 찾기 위해 ASCII 소문자로 바꾼 단어
+    lowercase: String,
+    /// 글자 위치마다 `lowercase` 의 바이트 위치
+    offsets: Vec<usize>,
+    /// 한글 음절이나 옛한글이 이어지는 구간
+    korean_runs: Vec<Range<usize>>,
+}
+
+impl TokenizedWord {
+    pub fn new(word: &str) -> Self {
+        let len = word.len();
+        let mut tokenized = Self {
+            chars: Vec::with_capacity(len),
+            types: Vec::with_capacity(len),
+            first_unsupported: None,
+            is_all_uppercase: true,
+            has_korean_char: false,
+            lowercase: String::with_capacity(len),
+            offsets: Vec::with_capacity(len),
+            korean_runs: Vec::new(),
+        };
+        for (i, c) in word.chars().enumerate() {
+            let char_type = CharType::new(c).ok();
+            if char_type.is_none() && tokenized.first_unsupported.is_none() {
+                tokenized.first_unsupported = Some(i);
+            }
+            if is_korean_char(c) {
+                tokenized.has_korean_char = true;
+                match tokenized.korean_runs.last_mut() {
+                    Some(run) if run.end == i => run.end = i + 1,
+                    _ => tokenized.korean_runs.push(i..i + 1),
+                }
+            }
+            tokenized.is_all_uppercase &= c.is_ascii_uppercase();
+            tokenized.offsets.push(tokenized.lowercase.len());
+            tokenized.lowercase.push(c.to_ascii_lowercase());
+            tokenized.chars.push(c);
+            tokenized.types.push(char_type);
+        }
+        tokenized
+    }
+
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// `index` 번째 글자부터 끝까지를 ASCII 소문자로 돌려줍니다.
+    pub fn lowercase_from(&self, index: usize) -> &str {
+        &self.lowercase[self.offsets[index]..]
+    }
+
+    /// `index` 번째 글자가 `kind` 종류인지 확인합니다. 단어 밖이면 `false` 입니다.
+    pub fn is_type(&self, index: usize, kind: fn(&CharType) -> bool) -> bool {
+        self.types
+            .get(index)
+            .is_some_and(|char_type| char_type.as_ref().is_some_and(kind))
+    }
+
+    /// `index` 번째 글자 앞에 한글(옛한글 포함)이 있는지 확인합니다.
+    pub fn has_korean_before(&self, index: usize) -> bool {
+        self.korean_runs
+            .first()
+            .is_some_and(|run| run.start < index)
+    }
+
+    /// `index` 번째 글자부터 찾아 처음 나오는 한글(옛한글 포함) 묶음
+    pub fn korean_after(&self, index: usize) -> Option<&[char]> {
+        let run = self.korean_runs.partition_point(|run| run.end <= index);
+        self.korean_runs
+            .get(run)
+            .map(|run| &self.chars[run.start.max(index)..run.end])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_tokenized_word() {
+        let word = TokenizedWord::new("Ab1+가나-😀다");
+        assert_eq!(word.len(), 9);
+        assert_eq!(word.first_unsupported, Some(7));
+        assert!(word.types[7].is_none());
+        assert!(word.has_korean_char);
+        assert!(!word.is_all_uppercase);
+        assert_eq!(word.lowercase_from(0), "ab1+가나-😀다");
+        assert_eq!(word.lowercase_from(5), "나-😀다");
+        assert!(word.is_type(0, |c| matches!(c, CharType::English(_))));
+        assert!(word.is_type(3, |c| matches!(c, CharType::MathSymbol(_))));
+        assert!(!word.is_type(9, |_| true));
+        assert!(!word.has_korean_before(4));
+        assert!(word.has_korean_before(5));
+        assert_eq!(word.korean_after(3), Some(&['가', '나'][..]));
+        assert_eq!(word.korean_after(5), Some(&['나'][..]));
+        assert_eq!(word.korean_after(6), Some(&['다'][..]));
+        assert_eq!(word.korean_after(8), Some(&['다'][..]));
+        assert_eq!(TokenizedWord::new("abc").korean_after(0), None);
+        // 옛한글도 한글로 봅니다.
+        let word = TokenizedWord::new("1ᄒᆞᆫ+ㆆ");
+        assert!(word.has_korean_char);
+        assert_eq!(word.korean_after(0), Some(&['ᄒ', 'ᆞ', 'ᆫ'][..]));
+        assert_eq!(word.korean_after(4), Some(&['ㆆ'][..]));
+        assert!(TokenizedWord::new("ABC").is_all_uppercase);
+    }
+}
@@ -12,6 +12,16 @@ pub const fn decode_unicode(text: char) -> u8 {
     (text as u32 - 0x2800) as u8
 }
 
+/// 점자 유니코드 문자를 셀로 바꿉니다. 줄바꿈은 255, 점자가 아닌 문자는 None 입니다.
+pub fn try_decode_unicode(text: char) -> Option<u8> {
+    match text {
+        '\n' => Some(255),
+        ' ' => Some(0),
+        '\u{2800}'..='\u{283F}' => Some((text as u32 - 0x2800) as u8),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -83,4 +93,15 @@ mod test {
         assert_eq!(encode_unicode(63), '⠿');
         assert_eq!(encode_unicode(255), '\n');
     }
+
+    #[test]
+    pub fn test_try_decode_unicode() {
+        for code in 0..64 {
+            assert_eq!(try_decode_unicode(encode_unicode(code)), Some(code));
+        }
+        assert_eq!(try_decode_unicode('\n'), Some(255));
+        assert_eq!(try_decode_unicode(' '), Some(0));
+        assert_eq!(try_decode_unicode('a'), None);
+        assert_eq!(try_decode_unicode('⣿'), None);
+    }
 }
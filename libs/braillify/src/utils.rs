@@ -21,6 +21,30 @@ pub fn build_char(choseong: char, jungseong: char, jongseong: Option<char>) -> c
     char::from_u32(hangul_code as u32).unwrap()
 }
 
+/// 점자 셀의 앞부분과 가장 길게 일치하는 항목을 찾습니다.
+pub fn longest_match<K: Copy>(
+    entries: impl Iterator<Item = (K, &'static [u8])>,
+    code: &[u8],
+) -> Option<(K, usize)> {
+    entries
+        .filter(|(_, value)| !value.is_empty() && code.starts_with(value))
+        .max_by_key(|(_, value)| value.len())
+        .map(|(key, value)| (key, value.len()))
+}
+
+/// 글자의 앞부분과 가장 길게 일치하는 약자를 찾습니다.
+///
+/// 긴 약자가 짧은 약자를 포함하면("the"와 "th", "ing"와 "in") 긴 약자를 고릅니다.
+/// 같은 길이의 서로 다른 약자는 한 글자에 함께 일치할 수 없으므로, 결과는 표의 순서(phf 해시 순서)와 무관합니다.
+pub fn longest_prefix<V: Copy>(
+    entries: impl Iterator<Item = (&'static str, V)>,
+    text: &str,
+) -> Option<(&'static str, V)> {
+    entries
+        .filter(|(key, _)| !key.is_empty() && text.starts_with(key))
+        .max_by_key(|(key, _)| key.len())
+}
+
 pub fn has_choseong_o(ch: char) -> bool {
     if let Ok(split) = split::split_korean_char(ch) {
         return split[0].get_char() == 'ㅇ';
@@ -38,12 +62,25 @@ mod test {
         assert_eq!(build_char('ㅇ', 'ㅏ', Some('ㄴ')), '안');
     }
 
+    #[test]
+    fn test_longest_prefix() {
+        let table = [("in", 1), ("ing", 2), ("th", 3), ("the", 4), ("", 5)];
+        for order in [table, [table[3], table[2], table[1], table[0], table[4]]] {
+            let find = |text| longest_prefix(order.into_iter(), text);
+            assert_eq!(find("sing"), None);
+            assert_eq!(find("ink"), Some(("in", 1)));
+            assert_eq!(find("ingot"), Some(("ing", 2)));
+            assert_eq!(find("this"), Some(("th", 3)));
+            assert_eq!(find("there"), Some(("the", 4)));
+        }
+    }
+
     #[test]
     fn test_has_choseong_o() {
-        assert_eq!(has_choseong_o('ㅇ'), true);
-        assert_eq!(has_choseong_o('ㄱ'), false);
-        assert_eq!(has_choseong_o('아'), true);
-        assert_eq!(has_choseong_o('가'), false);
-        assert_eq!(has_choseong_o('앙'), true);
+        assert!(has_choseong_o('ㅇ'));
+        assert!(!has_choseong_o('ㄱ'));
+        assert!(has_choseong_o('아'));
+        assert!(!has_choseong_o('가'));
+        assert!(has_choseong_o('앙'));
     }
 }
@@ -1,6 +1,11 @@
+use alloc::string::{String, ToString};
+
 use phf::phf_map;
 
-use crate::unicode::decode_unicode;
+use crate::{
+    unicode::decode_unicode,
+    utils::{longest_match, longest_prefix},
+};
 
 pub static SHORTCUT_MAP: phf::Map<&'static str, &'static [u8]> = phf_map! {
     "그래서" => &[decode_unicode('⠁'), decode_unicode('⠎')],
@@ -12,13 +17,14 @@ pub static SHORTCUT_MAP: phf::Map<&'static str, &'static [u8]> = phf_map! {
     "그리하여" => &[decode_unicode('⠁'), decode_unicode('⠱')],
 };
 
+/// 단어 앞부분과 가장 길게 일치하는 18항 약어와 나머지 글자를 돌려줍니다.
 pub fn split_word_shortcut(text: &str) -> Option<(&'static str, &'static [u8], String)> {
-    for (key, value) in SHORTCUT_MAP.entries() {
-        if text.starts_with(key) {
-            return Some((key, value, text[key.len()..].to_string()));
-        }
-    }
-    None
+    let (key, value) = longest_prefix(SHORTCUT_MAP.entries().map(|(k, v)| (*k, *v)), text)?;
+    Some((key, value, text[key.len()..].to_string()))
+}
+
+pub fn decode_word_shortcut(code: &[u8]) -> Option<(&'static str, usize)> {
+    longest_match(SHORTCUT_MAP.entries().map(|(k, v)| (*k, *v)), code)
 }
 
 #[cfg(test)]
@@ -36,4 +42,28 @@ mod test {
             )
         );
     }
+
+    #[test]
+    pub fn test_overlapping_word_shortcut() {
+        // 한 약어가 다른 약어로 시작하면 긴 약어를 골라야 합니다.
+        for long in SHORTCUT_MAP.keys() {
+            for short in SHORTCUT_MAP
+                .keys()
+                .filter(|short| long.starts_with(**short))
+            {
+                let text = format!("{long}는");
+                let (key, _, rest) = split_word_shortcut(&text).unwrap();
+                assert_eq!((key, rest.as_str()), (*long, "는"), "{short}");
+            }
+        }
+        assert_eq!(split_word_shortcut("그러니"), None);
+    }
+
+    #[test]
+    pub fn test_decode_word_shortcut() {
+        for (key, value) in SHORTCUT_MAP.entries() {
+            assert_eq!(decode_word_shortcut(value), Some((*key, 2)));
+        }
+        assert_eq!(decode_word_shortcut(&[decode_unicode('⠁')]), None);
+    }
 }
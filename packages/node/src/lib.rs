@@ -1,18 +1,194 @@
 mod utils;
 
+use braillify::{BrailleCell, BrailleError, Context, Newline, Unsupported};
 use wasm_bindgen::prelude::*;
 
+/// `BrailleError` 를 `name` 과 위치 정보를 담은 JS `Error` 로 바꿉니다.
+fn to_js_error(error: BrailleError) -> JsValue {
+    let js_error = js_sys::Error::new(&error.to_string());
+    js_error.set_name(match error {
+        BrailleError::UnsupportedCharacter { .. } => "UnsupportedCharacterError",
+        BrailleError::InvalidKorean { .. } => "InvalidKoreanError",
+        BrailleError::InvalidJamo { .. } => "InvalidJamoError",
+        BrailleError::InvalidEnglish { .. } => "InvalidEnglishError",
+        BrailleError::InvalidNumber { .. } => "InvalidNumberError",
+        BrailleError::InvalidSymbol { .. } => "InvalidSymbolError",
+        BrailleError::InvalidBrailleCell { .. } => "InvalidBrailleCellError",
+        BrailleError::InvalidBrailleCharacter { .. } => "InvalidBrailleCharacterError",
+        BrailleError::BufferTooSmall { .. } => "BufferTooSmallError",
+        _ => "BrailleError",
+    });
+    let set = |key: &str, value: JsValue| {
+        js_sys::Reflect::set(&js_error, &JsValue::from_str(key), &value).ok();
+    };
+    if let Some(character) = error.character() {
+        set("character", JsValue::from_str(&character.to_string()));
+    }
+    if let Some(offset) = error.offset() {
+        set("charOffset", JsValue::from(offset.char));
+        set("byteOffset", JsValue::from(offset.byte));
+    }
+    if let BrailleError::InvalidBrailleCell { cell, index } = error {
+        set("cell", JsValue::from(cell));
+        set("index", JsValue::from(index));
+    }
+    if let BrailleError::BufferTooSmall { required, capacity } = error {
+        set("required", JsValue::from(required));
+        set("capacity", JsValue::from(capacity));
+    }
+    js_error.into()
+}
+
 #[wasm_bindgen(js_name = "encode")]
-pub fn encode(text: &str) -> Result<Vec<u8>, String> {
-    braillify::encode(text)
+pub fn encode(text: &str) -> Result<Vec<u8>, JsValue> {
+    braillify::encode(text).map_err(to_js_error)
+}
+
+/// 점역 결과를 `buffer` 에 적고 적은 칸 수를 돌려줍니다.
+#[wasm_bindgen(js_name = "encodeInto")]
+pub fn encode_into(text: &str, buffer: &mut [u8]) -> Result<usize, JsValue> {
+    braillify::encode_into(text, buffer).map_err(to_js_error)
+}
+
+/// `encodeInto` 에 넘길 버퍼 크기, 점역 결과보다 작지 않습니다.
+#[wasm_bindgen(js_name = "requiredLen")]
+pub fn required_len(text: &str) -> usize {
+    braillify::required_len(text)
 }
 
 #[wasm_bindgen(js_name = "translateToUnicode")]
-pub fn translate_to_unicode(text: &str) -> Result<String, String> {
-    braillify::encode_to_unicode(text)
+pub fn translate_to_unicode(text: &str) -> Result<String, JsValue> {
+    braillify::encode_to_unicode(text).map_err(to_js_error)
 }
 
 #[wasm_bindgen(js_name = "translateToBrailleFont")]
-pub fn translate_to_braille_font(text: &str) -> Result<String, String> {
-    braillify::encode_to_braille_font(text)
+pub fn translate_to_braille_font(text: &str) -> Result<String, JsValue> {
+    braillify::encode_to_braille_font(text).map_err(to_js_error)
+}
+
+/// 점자 프린터(엠보서)에 보낼 `.brf` 파일 내용, 줄은 CRLF 로 끝납니다.
+#[wasm_bindgen(js_name = "translateToBrf")]
+pub fn translate_to_brf(text: &str) -> Result<String, JsValue> {
+    braillify::encode_to_brf(text).map_err(to_js_error)
+}
+
+/// PEF(Portable Embosser Format) 1.0 문서, 칸 수와 줄 수를 생략하면 40칸 25줄입니다.
+#[wasm_bindgen(js_name = "translateToPef")]
+pub fn translate_to_pef(
+    text: &str,
+    title: &str,
+    cells_per_row: Option<usize>,
+    rows_per_page: Option<usize>,
+) -> Result<String, JsValue> {
+    let mut options = braillify::PefOptions::new(title);
+    if let Some(cells) = cells_per_row {
+        options = options.cells_per_row(cells);
+    }
+    if let Some(rows) = rows_per_page {
+        options = options.rows_per_page(rows);
+    }
+    braillify::encode_to_pef(text, &options).map_err(to_js_error)
+}
+
+#[wasm_bindgen(js_name = "decode")]
+pub fn decode(cells: &[u8]) -> Result<String, JsValue> {
+    braillify::decode(cells).map_err(to_js_error)
+}
+
+#[wasm_bindgen(js_name = "decodeUncontracted")]
+pub fn decode_uncontracted(cells: &[u8]) -> Result<String, JsValue> {
+    braillify::decode_uncontracted(cells).map_err(to_js_error)
+}
+
+#[wasm_bindgen(js_name = "translateFromUnicode")]
+pub fn translate_from_unicode(text: &str) -> Result<String, JsValue> {
+    braillify::decode_from_unicode(text).map_err(to_js_error)
+}
+
+/// 점역 옵션, `new EncodeOptions().context("korean").koreanContractions(false)` 처럼 이어 씁니다.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct EncodeOptions(braillify::EncodeOptions);
+
+#[wasm_bindgen]
+impl EncodeOptions {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 풀어쓰기, 한글 약자와 약어를 쓰지 않습니다.
+    pub fn uncontracted() -> EncodeOptions {
+        Self(braillify::EncodeOptions::uncontracted())
+    }
+
+    /// "auto" | "korean" | "english"
+    pub fn context(self, context: &str) -> Result<EncodeOptions, JsValue> {
+        let context = match context {
+            "auto" => Context::Auto,
+            "korean" => Context::Korean,
+            "english" => Context::English,
+            _ => return Err(invalid_option("context", context)),
+        };
+        Ok(Self(self.0.context(context)))
+    }
+
+    #[wasm_bindgen(js_name = "koreanContractions")]
+    pub fn korean_contractions(self, enabled: bool) -> EncodeOptions {
+        Self(self.0.korean_contractions(enabled))
+    }
+
+    #[wasm_bindgen(js_name = "englishContractions")]
+    pub fn english_contractions(self, enabled: bool) -> EncodeOptions {
+        Self(self.0.english_contractions(enabled))
+    }
+
+    /// "keep" | "space"
+    pub fn newline(self, newline: &str) -> Result<EncodeOptions, JsValue> {
+        let newline = match newline {
+            "keep" => Newline::Keep,
+            "space" => Newline::Space,
+            _ => return Err(invalid_option("newline", newline)),
+        };
+        Ok(Self(self.0.newline(newline)))
+    }
+
+    /// "error" | "skip" | "replace", "replace" 는 `replacement` 셀로 바꿉니다.
+    pub fn unsupported(
+        self,
+        policy: &str,
+        replacement: Option<u8>,
+    ) -> Result<EncodeOptions, JsValue> {
+        let unsupported = match (policy, replacement) {
+            ("error", _) => Unsupported::Error,
+            ("skip", _) => Unsupported::Skip,
+            ("replace", Some(cell)) if let Some(cell) = BrailleCell::new(cell) => {
+                Unsupported::Replace(cell)
+            }
+            _ => return Err(invalid_option("unsupported", policy)),
+        };
+        Ok(Self(self.0.unsupported(unsupported)))
+    }
+
+    /// 풀어 쓴 한글 자모, 전각과 반각 글자를 보통 글자로 바꾼 뒤 점역합니다. (기본값 `true`)
+    pub fn normalize(self, enabled: bool) -> EncodeOptions {
+        Self(self.0.normalize(enabled))
+    }
+}
+
+fn invalid_option(name: &str, value: &str) -> JsValue {
+    js_sys::RangeError::new(&format!("Invalid {name} option: {value}")).into()
+}
+
+#[wasm_bindgen(js_name = "encodeWithOptions")]
+pub fn encode_with_options(text: &str, options: &EncodeOptions) -> Result<Vec<u8>, JsValue> {
+    braillify::encode_with_options(text, &options.0).map_err(to_js_error)
+}
+
+#[wasm_bindgen(js_name = "translateToUnicodeWithOptions")]
+pub fn translate_to_unicode_with_options(
+    text: &str,
+    options: &EncodeOptions,
+) -> Result<String, JsValue> {
+    braillify::encode_to_unicode_with_options(text, &options.0).map_err(to_js_error)
 }
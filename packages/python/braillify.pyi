@@ -2,7 +2,41 @@
 # ruff: noqa: E501, F401
 
 import builtins
+import typing
 
 def encode(text: str) -> builtins.list[int]: ...
 def translate_to_unicode(text: str) -> str: ...
 def translate_to_braille_font(text: str) -> str: ...
+def translate_to_brf(text: str) -> str: ...
+def translate_to_pef(
+    text: str, title: str = "", cells_per_row: int = 40, rows_per_page: int = 25
+) -> str: ...
+def decode(cells: builtins.list[int]) -> str: ...
+def decode_uncontracted(cells: builtins.list[int]) -> str: ...
+def translate_from_unicode(text: str) -> str: ...
+def encode_with_options(text: str, options: EncodeOptions) -> builtins.list[int]: ...
+def translate_to_unicode_with_options(text: str, options: EncodeOptions) -> str: ...
+
+class EncodeOptions:
+    def __new__(
+        cls,
+        context: str = "auto",
+        korean_contractions: bool = True,
+        english_contractions: bool = True,
+        newline: str = "keep",
+        unsupported: str = "error",
+        replacement: typing.Optional[int] = None,
+        normalize: bool = True,
+    ) -> EncodeOptions: ...
+    @staticmethod
+    def uncontracted() -> EncodeOptions: ...
+
+class BrailleError(builtins.ValueError): ...
+class UnsupportedCharacterError(BrailleError): ...
+class InvalidKoreanError(BrailleError): ...
+class InvalidJamoError(BrailleError): ...
+class InvalidEnglishError(BrailleError): ...
+class InvalidNumberError(BrailleError): ...
+class InvalidSymbolError(BrailleError): ...
+class InvalidBrailleCellError(BrailleError): ...
+class InvalidBrailleCharacterError(BrailleError): ...
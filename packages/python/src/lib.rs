@@ -1,27 +1,208 @@
 use braillify as braillify_core;
+use braillify_core::BrailleError as CoreBrailleError;
+use braillify_core::{BrailleCell, Context, Newline, Unsupported};
+use pyo3::create_exception;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
+create_exception!(braillify, BrailleError, PyValueError);
+create_exception!(braillify, UnsupportedCharacterError, BrailleError);
+create_exception!(braillify, InvalidKoreanError, BrailleError);
+create_exception!(braillify, InvalidJamoError, BrailleError);
+create_exception!(braillify, InvalidEnglishError, BrailleError);
+create_exception!(braillify, InvalidNumberError, BrailleError);
+create_exception!(braillify, InvalidSymbolError, BrailleError);
+create_exception!(braillify, InvalidBrailleCellError, BrailleError);
+create_exception!(braillify, InvalidBrailleCharacterError, BrailleError);
+
+/// 예외의 args 는 (메시지, 문자, 문자 위치, 바이트 위치) 이고
+/// `InvalidBrailleCellError` 는 (메시지, 셀, 셀 위치) 입니다.
+/// 파이썬에는 버퍼에 점역하는 함수가 없으므로 `BufferTooSmall` 은 나오지 않습니다.
+fn to_py_err(error: CoreBrailleError) -> PyErr {
+    let message = error.to_string();
+    let (character, offset) = (error.character(), error.offset().unwrap_or_default());
+    let args = (message, character, offset.char, offset.byte);
+    match error {
+        CoreBrailleError::UnsupportedCharacter { .. } => UnsupportedCharacterError::new_err(args),
+        CoreBrailleError::InvalidKorean { .. } => InvalidKoreanError::new_err(args),
+        CoreBrailleError::InvalidJamo { .. } => InvalidJamoError::new_err(args),
+        CoreBrailleError::InvalidEnglish { .. } => InvalidEnglishError::new_err(args),
+        CoreBrailleError::InvalidNumber { .. } => InvalidNumberError::new_err(args),
+        CoreBrailleError::InvalidSymbol { .. } => InvalidSymbolError::new_err(args),
+        CoreBrailleError::InvalidBrailleCell { cell, index } => {
+            InvalidBrailleCellError::new_err((args.0, cell, index))
+        }
+        CoreBrailleError::InvalidBrailleCharacter { .. } => {
+            InvalidBrailleCharacterError::new_err(args)
+        }
+        _ => BrailleError::new_err(args),
+    }
+}
+
 #[pyfunction]
 fn encode(text: &str) -> PyResult<Vec<u8>> {
-    braillify_core::encode(text).map_err(|e| PyErr::new::<PyValueError, _>(e))
+    braillify_core::encode(text).map_err(to_py_err)
 }
 
 #[pyfunction]
 fn translate_to_unicode(text: &str) -> PyResult<String> {
-    braillify_core::encode_to_unicode(text).map_err(|e| PyErr::new::<PyValueError, _>(e))
+    braillify_core::encode_to_unicode(text).map_err(to_py_err)
 }
 
 #[pyfunction]
 fn translate_to_braille_font(text: &str) -> PyResult<String> {
-    braillify_core::encode_to_braille_font(text).map_err(|e| PyErr::new::<PyValueError, _>(e))
+    braillify_core::encode_to_braille_font(text).map_err(to_py_err)
+}
+
+/// 점자 프린터(엠보서)에 보낼 `.brf` 파일 내용, 줄은 CRLF 로 끝납니다.
+#[pyfunction]
+fn translate_to_brf(text: &str) -> PyResult<String> {
+    braillify_core::encode_to_brf(text).map_err(to_py_err)
+}
+
+#[pyfunction]
+fn decode(cells: Vec<u8>) -> PyResult<String> {
+    braillify_core::decode(&cells).map_err(to_py_err)
+}
+
+#[pyfunction]
+fn decode_uncontracted(cells: Vec<u8>) -> PyResult<String> {
+    braillify_core::decode_uncontracted(&cells).map_err(to_py_err)
+}
+
+#[pyfunction]
+fn translate_from_unicode(text: &str) -> PyResult<String> {
+    braillify_core::decode_from_unicode(text).map_err(to_py_err)
+}
+
+/// 점역 옵션
+#[pyclass(frozen)]
+#[derive(Clone)]
+struct EncodeOptions(braillify_core::EncodeOptions);
+
+#[pymethods]
+impl EncodeOptions {
+    /// context: "auto" | "korean" | "english"
+    /// newline: "keep" | "space"
+    /// unsupported: "error" | "skip" | "replace", "replace" 는 replacement 셀로 바꿉니다.
+    /// normalize: 풀어 쓴 한글 자모, 전각과 반각 글자를 보통 글자로 바꾼 뒤 점역합니다.
+    #[new]
+    #[pyo3(signature = (
+        context = "auto",
+        korean_contractions = true,
+        english_contractions = true,
+        newline = "keep",
+        unsupported = "error",
+        replacement = None,
+        normalize = true,
+    ))]
+    fn new(
+        context: &str,
+        korean_contractions: bool,
+        english_contractions: bool,
+        newline: &str,
+        unsupported: &str,
+        replacement: Option<u8>,
+        normalize: bool,
+    ) -> PyResult<Self> {
+        let context = match context {
+            "auto" => Context::Auto,
+            "korean" => Context::Korean,
+            "english" => Context::English,
+            _ => return Err(invalid_option("context", context)),
+        };
+        let newline = match newline {
+            "keep" => Newline::Keep,
+            "space" => Newline::Space,
+            _ => return Err(invalid_option("newline", newline)),
+        };
+        let unsupported = match (unsupported, replacement) {
+            ("error", _) => Unsupported::Error,
+            ("skip", _) => Unsupported::Skip,
+            ("replace", Some(cell)) if let Some(cell) = BrailleCell::new(cell) => {
+                Unsupported::Replace(cell)
+            }
+            _ => return Err(invalid_option("unsupported", unsupported)),
+        };
+        Ok(Self(
+            braillify_core::EncodeOptions::new()
+                .context(context)
+                .korean_contractions(korean_contractions)
+                .english_contractions(english_contractions)
+                .newline(newline)
+                .unsupported(unsupported)
+                .normalize(normalize),
+        ))
+    }
+
+    /// 풀어쓰기, 한글 약자와 약어를 쓰지 않습니다.
+    #[staticmethod]
+    fn uncontracted() -> Self {
+        Self(braillify_core::EncodeOptions::uncontracted())
+    }
+}
+
+fn invalid_option(name: &str, value: &str) -> PyErr {
+    PyValueError::new_err(format!("Invalid {name} option: {value}"))
+}
+
+#[pyfunction]
+fn encode_with_options(text: &str, options: &EncodeOptions) -> PyResult<Vec<u8>> {
+    braillify_core::encode_with_options(text, &options.0).map_err(to_py_err)
+}
+
+#[pyfunction]
+fn translate_to_unicode_with_options(text: &str, options: &EncodeOptions) -> PyResult<String> {
+    braillify_core::encode_to_unicode_with_options(text, &options.0).map_err(to_py_err)
+}
+
+/// PEF(Portable Embosser Format) 1.0 문서로 점역합니다.
+#[pyfunction]
+#[pyo3(signature = (text, title = "", cells_per_row = 40, rows_per_page = 25))]
+fn translate_to_pef(
+    text: &str,
+    title: &str,
+    cells_per_row: usize,
+    rows_per_page: usize,
+) -> PyResult<String> {
+    let options = braillify_core::PefOptions::new(title)
+        .cells_per_row(cells_per_row)
+        .rows_per_page(rows_per_page);
+    braillify_core::encode_to_pef(text, &options).map_err(to_py_err)
 }
 
 /// A Python module implemented in Rust.
 #[pymodule(name = "braillify")]
 fn lib_braillify(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
     m.add_function(wrap_pyfunction!(encode, m)?)?;
     m.add_function(wrap_pyfunction!(translate_to_unicode, m)?)?;
     m.add_function(wrap_pyfunction!(translate_to_braille_font, m)?)?;
+    m.add_function(wrap_pyfunction!(translate_to_brf, m)?)?;
+    m.add_function(wrap_pyfunction!(translate_to_pef, m)?)?;
+    m.add_function(wrap_pyfunction!(decode, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_uncontracted, m)?)?;
+    m.add_function(wrap_pyfunction!(translate_from_unicode, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_with_options, m)?)?;
+    m.add_function(wrap_pyfunction!(translate_to_unicode_with_options, m)?)?;
+    m.add_class::<EncodeOptions>()?;
+    m.add("BrailleError", py.get_type::<BrailleError>())?;
+    m.add(
+        "UnsupportedCharacterError",
+        py.get_type::<UnsupportedCharacterError>(),
+    )?;
+    m.add("InvalidKoreanError", py.get_type::<InvalidKoreanError>())?;
+    m.add("InvalidJamoError", py.get_type::<InvalidJamoError>())?;
+    m.add("InvalidEnglishError", py.get_type::<InvalidEnglishError>())?;
+    m.add("InvalidNumberError", py.get_type::<InvalidNumberError>())?;
+    m.add("InvalidSymbolError", py.get_type::<InvalidSymbolError>())?;
+    m.add(
+        "InvalidBrailleCellError",
+        py.get_type::<InvalidBrailleCellError>(),
+    )?;
+    m.add(
+        "InvalidBrailleCharacterError",
+        py.get_type::<InvalidBrailleCharacterError>(),
+    )?;
     Ok(())
 }
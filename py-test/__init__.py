@@ -10,3 +10,41 @@ import braillify
 )
 def test_encode(input, expected):
     assert braillify.translate_to_unicode(input) == expected
+
+
+def test_unsupported_character_error():
+    with pytest.raises(braillify.UnsupportedCharacterError) as info:
+        braillify.translate_to_unicode("가😀")
+    assert info.value.args == (
+        "Unsupported character '😀' at char 1 (byte 3)",
+        "😀",
+        1,
+        3,
+    )
+    assert isinstance(info.value, braillify.BrailleError)
+    assert isinstance(info.value, ValueError)
+
+
+def test_invalid_braille_errors():
+    with pytest.raises(braillify.InvalidBrailleCellError) as info:
+        braillify.decode([1, 64])
+    assert info.value.args == ("Invalid braille cell 64 at index 1", 64, 1)
+    with pytest.raises(braillify.InvalidBrailleCharacterError) as info:
+        braillify.translate_from_unicode("⠁a")
+    assert info.value.args[1:] == ("a", 1, 3)
+
+
+def test_error_classes():
+    # 버퍼에 점역하는 함수가 없으므로 BufferTooSmallError 도 없습니다.
+    assert not hasattr(braillify, "BufferTooSmallError")
+    for name in [
+        "UnsupportedCharacterError",
+        "InvalidKoreanError",
+        "InvalidJamoError",
+        "InvalidEnglishError",
+        "InvalidNumberError",
+        "InvalidSymbolError",
+        "InvalidBrailleCellError",
+        "InvalidBrailleCharacterError",
+    ]:
+        assert issubclass(getattr(braillify, name), braillify.BrailleError)